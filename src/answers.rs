@@ -0,0 +1,216 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Error, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::solution::Part;
+
+/// Written at the top of `answers.toml` every time it is saved.
+const HEADER: &str = "\
+# Answers to the personal puzzle inputs, checked by `aoc run` and `cargo test`.
+# Add one with `cargo run --bin aoc -- record <day>` after earning its star.
+
+";
+
+/// Answers to the personal puzzle inputs, one table per day:
+///
+/// ```toml
+/// [day01]
+/// part_1 = "936063"
+/// part_2 = "23150395"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part_1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part_2: Option<String>,
+}
+
+impl DayAnswers {
+    fn part(&self, part: Part) -> &Option<String> {
+        match part {
+            Part::One => &self.part_1,
+            Part::Two => &self.part_2,
+        }
+    }
+
+    fn part_mut(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part_1,
+            Part::Two => &mut self.part_2,
+        }
+    }
+}
+
+/// How a computed answer compares with the recorded one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check<'a> {
+    /// Nothing recorded for this part yet.
+    Unrecorded,
+    Correct,
+    Wrong {
+        expected: &'a str,
+    },
+}
+
+/// Path of `answers.toml` at the root of the repository.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+fn key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+impl Answers {
+    /// Reads the answers stored at `path`, a missing file holding none.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read `{}`", path.display()))
+            }
+        };
+
+        text.parse()
+            .with_context(|| format!("`{}` is not a valid answers file", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let text = format!("{HEADER}{}", toml::to_string(self)?);
+        fs::write(path, text).with_context(|| format!("failed to write `{}`", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.days.get(&key(day))?.part(part).as_deref()
+    }
+
+    /// Records `answer` for a part, returning the answer it replaced.
+    pub fn insert(&mut self, day: u8, part: Part, answer: String) -> Option<String> {
+        self.days
+            .entry(key(day))
+            .or_default()
+            .part_mut(part)
+            .replace(answer)
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Check<'_> {
+        match self.get(day, part) {
+            None => Check::Unrecorded,
+            Some(expected) if expected == answer => Check::Correct,
+            Some(expected) => Check::Wrong { expected },
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let answers: Answers = toml::from_str(s)?;
+
+        if let Some(table) = answers
+            .days
+            .keys()
+            .find(|table| !(1..=25).any(|day| **table == key(day)))
+        {
+            bail!("unknown table `[{table}]`, expected `[day01]` to `[day25]`");
+        }
+
+        Ok(answers)
+    }
+}
+
+#[test]
+fn round_trips_through_toml() {
+    let mut answers = Answers::default();
+    assert_eq!(answers.insert(1, Part::One, "11".to_string()), None);
+    assert_eq!(
+        answers.insert(25, Part::Two, "Merry Christmas".to_string()),
+        None
+    );
+    assert_eq!(
+        answers.insert(1, Part::One, "31".to_string()),
+        Some("11".to_string())
+    );
+
+    let text = toml::to_string(&answers).unwrap();
+    assert_eq!(
+        text,
+        "[day01]\npart_1 = \"31\"\n\n[day25]\npart_2 = \"Merry Christmas\"\n"
+    );
+    let parsed: Answers = text.parse().unwrap();
+    assert_eq!(parsed, answers);
+
+    assert_eq!(parsed.check(1, Part::One, "31"), Check::Correct);
+    assert_eq!(
+        parsed.check(1, Part::One, "11"),
+        Check::Wrong { expected: "31" }
+    );
+    assert_eq!(parsed.check(1, Part::Two, "11"), Check::Unrecorded);
+
+    assert!("[day26]\npart_1 = \"1\"\n".parse::<Answers>().is_err());
+    assert!("[day01]\npart_3 = \"1\"\n".parse::<Answers>().is_err());
+}
+
+/// Solves every recorded part on the personal inputs, so that a change which
+/// breaks a real answer fails `cargo test`. Days whose input is not on disk
+/// are skipped.
+#[test]
+fn recorded_answers_still_hold() {
+    use rayon::prelude::*;
+
+    use crate::days;
+    use crate::input::{self, InputSource};
+
+    let answers = Answers::load(&default_path()).unwrap();
+
+    let mut mismatches: Vec<String> = days::ALL
+        .par_iter()
+        .flat_map_iter(|solution| {
+            let day = solution.day();
+            let recorded: Vec<_> = [Part::One, Part::Two]
+                .into_iter()
+                .filter_map(|part| Some((part, answers.get(day, part)?)))
+                .collect();
+            if recorded.is_empty() {
+                return Vec::new();
+            }
+
+            let Ok(input) = input::load(day, &InputSource::Default) else {
+                return Vec::new();
+            };
+            let parsed = match solution.parse(&input) {
+                Ok(parsed) => parsed,
+                Err(err) => return vec![format!("day {day:02}: {err:#}")],
+            };
+
+            recorded
+                .into_iter()
+                .filter_map(|(part, expected)| match parsed.solve(part) {
+                    Ok(answer) if answer == expected => None,
+                    Ok(answer) => Some(format!(
+                        "day {day:02} part {part}: got {answer}, expected {expected}"
+                    )),
+                    Err(err) => Some(format!("day {day:02} part {part}: {err:#}")),
+                })
+                .collect()
+        })
+        .collect();
+    mismatches.sort();
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
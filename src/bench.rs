@@ -0,0 +1,212 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Error, Result};
+
+use crate::input::{self, InputSource};
+use crate::runner::{DaySelection, PartSelection};
+use crate::solution::DynSolution;
+
+/// How many times each step runs: `warmup` untimed runs, then `runs` timed ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: 10,
+        }
+    }
+}
+
+/// Summary of the timed runs of one step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarizes `samples`, or returns `None` when there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let min = *sorted.first()?;
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        // sample standard deviation, zero for a single run
+        let variance = if n > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            min,
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Runs `step` `warmup` times, then times it `runs` times.
+///
+/// Each run is given a fresh value made by `setup` outside the timing, so a
+/// parsed input caching what it computed, like the one of day 11, cannot
+/// make the later runs look faster.
+fn measure<S, T>(
+    options: BenchOptions,
+    mut setup: impl FnMut() -> Result<S, Error>,
+    mut step: impl FnMut(&S) -> Result<T, Error>,
+) -> Result<Stats, Error> {
+    for _ in 0..options.warmup {
+        black_box(step(&setup()?)?);
+    }
+
+    let mut samples = Vec::with_capacity(options.runs);
+    for _ in 0..options.runs {
+        let value = setup()?;
+        let start = Instant::now();
+        black_box(step(&value)?);
+        samples.push(start.elapsed());
+    }
+
+    Stats::from_samples(&samples).ok_or_else(|| Error::msg("no timed runs"))
+}
+
+/// Benchmarks the parsing and the selected parts of the selected days,
+/// printing one table row per step.
+///
+/// Days without a usable input are listed and skipped, while parse or solve
+/// errors make the whole benchmark fail once the table is complete.
+pub fn bench(
+    days: DaySelection,
+    parts: PartSelection,
+    source: &InputSource,
+    options: BenchOptions,
+) -> Result<(), Error> {
+    if options.runs == 0 {
+        bail!("`--runs` must be at least 1");
+    }
+    if days == DaySelection::All && matches!(source, InputSource::File(_) | InputSource::Stdin) {
+        bail!("`--input` applies to a single day, each day of `all` reads its own input");
+    }
+
+    println!(
+        "\n{} warmup run(s), {} timed run(s) per step\n",
+        options.warmup, options.runs
+    );
+    println!(
+        "{:<4} {:<16} {:>10} {:>10} {:>10} {:>10}",
+        "Day", "Step", "Min", "Median", "Mean", "Std dev"
+    );
+    println!("{}", "-".repeat(4 + 1 + 16 + 4 * 11));
+
+    let mut failures = 0;
+    for solution in days.solutions()? {
+        failures += bench_day(solution, parts, source, options);
+    }
+
+    println!();
+
+    if failures > 0 {
+        bail!("{failures} step(s) failed");
+    }
+
+    Ok(())
+}
+
+/// Prints the rows of one day, returning how many steps failed.
+fn bench_day(
+    solution: &dyn DynSolution,
+    parts: PartSelection,
+    source: &InputSource,
+    options: BenchOptions,
+) -> usize {
+    let day = solution.day();
+
+    let input = match input::load(day, source) {
+        Ok(input) => input,
+        Err(err) => {
+            println!("{day:<4} skipped: {err:#}");
+            return 0;
+        }
+    };
+
+    let parse = measure(options, || Ok(()), |()| solution.parse(&input));
+    print_row(day, "parse", &parse);
+    if parse.is_err() {
+        return 1;
+    }
+
+    let mut failures = 0;
+    for &part in parts.parts() {
+        let step = format!("part {}", part.number());
+        let stats = measure(
+            options,
+            || solution.parse(&input),
+            |parsed| parsed.solve(part),
+        );
+        if stats.is_err() {
+            failures += 1;
+        }
+        print_row(day, &step, &stats);
+
+        // Variants of the part right below it, to compare them
+        for (of, name) in solution.variants() {
+            if of != part {
+                continue;
+            }
+            let stats = measure(
+                options,
+                || solution.parse(&input),
+                |parsed| parsed.solve_variant(part, name),
+            );
+            if stats.is_err() {
+                failures += 1;
+            }
+            print_row(day, &format!("{step} {name}"), &stats);
+        }
+    }
+
+    failures
+}
+
+fn print_row(day: u8, step: &str, stats: &Result<Stats, Error>) {
+    match stats {
+        Ok(stats) => println!(
+            "{day:<4} {step:<16} {:>10.2?} {:>10.2?} {:>10.2?} {:>10.2?}",
+            stats.min, stats.median, stats.mean, stats.stddev
+        ),
+        Err(err) => println!("{day:<4} {step:<16} error: {err:#}"),
+    }
+}
+
+#[test]
+fn stats_from_samples() {
+    let samples = [4, 1, 3, 2].map(Duration::from_millis);
+    let stats = Stats::from_samples(&samples).unwrap();
+
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_micros(2500));
+    assert_eq!(stats.mean, Duration::from_micros(2500));
+    // sqrt(5 / 3) milliseconds
+    assert_eq!(stats.stddev.as_micros(), 1290);
+
+    assert_eq!(Stats::from_samples(&[]), None);
+}
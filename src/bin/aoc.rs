@@ -0,0 +1,276 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use anyhow::{bail, Context, Error, Result};
+use clap::{Parser, Subcommand};
+
+use aoc_for_rustaceans::bench::{self, BenchOptions};
+use aoc_for_rustaceans::differential::DiffOptions;
+use aoc_for_rustaceans::generate;
+use aoc_for_rustaceans::input::InputSource;
+use aoc_for_rustaceans::runner::{self, DaySelection, Format, PartSelection, RunOptions};
+use aoc_for_rustaceans::scaffold::{self, Outcome};
+use aoc_for_rustaceans::solution::Part;
+use aoc_for_rustaceans::visualize::{FrameFormat, Settings, Target};
+
+/// Advent of Code 2024 solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day with `all`
+    Run {
+        /// Day from 1 to 25, or `all`
+        day: DaySelection,
+        /// Part to solve: `1`, `2` or `both`
+        #[arg(long, default_value = "both")]
+        part: PartSelection,
+        /// Puzzle input file, `-` for stdin [default: puzzle_input/day_XX.txt]
+        #[arg(long, value_name = "FILE")]
+        input: Option<InputSource>,
+        /// Output: `text`, or `json` with one object per part
+        #[arg(long, default_value = "text")]
+        format: Format,
+        /// Solve the days in parallel, which makes the timings noisier
+        #[arg(long)]
+        parallel: bool,
+        /// Show the steps of the solvers in the terminal, record them in a
+        /// `.gif` file, or write them to a directory
+        #[arg(long, value_name = "DIR|FILE.gif", num_args = 0..=1, default_missing_value = "terminal")]
+        visualize: Option<Target>,
+        /// Format of the frames written to a directory: `txt`, `ppm` or `png`
+        #[arg(long, default_value = "txt", requires = "visualize")]
+        frame_format: FrameFormat,
+        /// Keep one frame out of N, and the last one
+        #[arg(long, value_name = "N", default_value_t = 1, requires = "visualize")]
+        every: usize,
+    },
+    /// Step through the simulation of day 6, 14 or 15 one tick at a time
+    Step {
+        /// Day from 1 to 25
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part to simulate: `1` or `2`
+        #[arg(long, default_value = "1")]
+        part: Part,
+        /// Puzzle input file, `-` for stdin [default: puzzle_input/day_XX.txt]
+        #[arg(long, value_name = "FILE")]
+        input: Option<InputSource>,
+    },
+    /// Time the parsing and each part of one day, or every day with `all`
+    Bench {
+        /// Day from 1 to 25, or `all`
+        day: DaySelection,
+        /// Part to time: `1`, `2` or `both`
+        #[arg(long, default_value = "both")]
+        part: PartSelection,
+        /// Puzzle input file, `-` for stdin [default: puzzle_input/day_XX.txt]
+        #[arg(long, value_name = "FILE", conflicts_with = "generate")]
+        input: Option<InputSource>,
+        /// Time a random input of this size instead, see the `gen` command
+        #[arg(long, value_name = "SIZE")]
+        generate: Option<usize>,
+        /// Seed of the random input
+        #[arg(long, default_value_t = 0, requires = "generate")]
+        seed: u64,
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = BenchOptions::default().warmup)]
+        warmup: usize,
+        /// Timed runs of each step
+        #[arg(long, default_value_t = BenchOptions::default().runs)]
+        runs: usize,
+    },
+    /// Write a random input of a day, to see how its solver scales
+    Gen {
+        /// Day from 1 to 25
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// How big the input is, in a unit that depends on the day
+        #[arg(long)]
+        size: usize,
+        /// Seed of the random input, the same seed giving the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// File to write [default: stdout]
+        #[arg(long, short, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+    /// Check the variants of a day against its main solvers on random inputs
+    Diff {
+        /// Day from 1 to 25
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// How many random inputs to try
+        #[arg(long, default_value_t = DiffOptions::default().cases)]
+        cases: usize,
+        /// Size of the random inputs, as for `gen`
+        #[arg(long, default_value_t = DiffOptions::default().size)]
+        size: usize,
+        /// Seed of the first input, the next ones counting up from it
+        #[arg(long, default_value_t = DiffOptions::default().seed)]
+        seed: u64,
+    },
+    /// Store the answers to the personal input in `answers.toml`
+    Record {
+        /// Day from 1 to 25, or `all`
+        day: DaySelection,
+        /// Part to record: `1`, `2` or `both`
+        #[arg(long, default_value = "both")]
+        part: PartSelection,
+        /// Replace answers that differ from the recorded ones
+        #[arg(long)]
+        force: bool,
+    },
+    /// Download the personal input of one day, or every day with `all`
+    Fetch {
+        /// Day from 1 to 25, or `all`
+        day: DaySelection,
+        /// Replace an input already saved in `puzzle_input/`
+        #[arg(long)]
+        force: bool,
+    },
+    /// Submit an answer to adventofcode.com
+    Submit {
+        /// Day from 1 to 25
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part to answer: `1` or `2`
+        #[arg(long)]
+        part: Part,
+        /// Answer to submit [default: the answer computed from the personal input]
+        answer: Option<String>,
+    },
+    /// Extract the examples of a day from its description into `examples/`
+    Examples {
+        /// Day from 1 to 25
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Puzzle page saved from the site [default: download it]
+        page: Option<PathBuf>,
+        /// Replace the examples already extracted
+        #[arg(long)]
+        force: bool,
+    },
+    /// Create the solution module, binary and input file of a day
+    NewDay {
+        /// Day from 1 to 25
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Replace a solution module that no longer holds the template
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    match execute(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        // Days already printed their own diagnostics, a backtrace would bury them
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute(cli: Cli) -> Result<(), Error> {
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            parallel,
+            visualize,
+            frame_format,
+            every,
+        } => runner::run(
+            day,
+            part,
+            &RunOptions {
+                source: input.unwrap_or_default(),
+                format,
+                parallel,
+                visualize: visualize.map(|target| Settings {
+                    target,
+                    format: frame_format,
+                    every,
+                }),
+            },
+        ),
+        Command::Step { day, part, input } => runner::step(day, part, &input.unwrap_or_default()),
+        Command::Bench {
+            day,
+            part,
+            input,
+            generate,
+            seed,
+            warmup,
+            runs,
+        } => {
+            let source = match generate {
+                Some(size) => InputSource::Generated { size, seed },
+                None => input.unwrap_or_default(),
+            };
+            bench::bench(day, part, &source, BenchOptions { warmup, runs })
+        }
+        Command::Gen {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let input = generate::generate(day, size, seed)?;
+            match output {
+                Some(path) => fs::write(&path, input)
+                    .with_context(|| format!("failed to write `{}`", path.display())),
+                None => {
+                    print!("{input}");
+                    Ok(())
+                }
+            }
+        }
+        Command::Diff {
+            day,
+            cases,
+            size,
+            seed,
+        } => runner::diff(day, DiffOptions { cases, size, seed }),
+        Command::Record { day, part, force } => runner::record(day, part, force),
+        Command::Fetch { day, force } => runner::fetch(day, force),
+        Command::Submit { day, part, answer } => runner::submit(day, part, answer),
+        Command::Examples { day, page, force } => {
+            runner::extract_examples(day, page.as_deref(), force)
+        }
+        Command::NewDay { day, force } => new_day(day, force),
+    }
+}
+
+fn new_day(day: u8, force: bool) -> Result<(), Error> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for outcome in scaffold::new_day(root, day, force)? {
+        match outcome {
+            Outcome::Created(path) => println!("created {}", relative(root, &path)),
+            Outcome::Unchanged(path) => println!("kept {}", relative(root, &path)),
+            Outcome::Replaced(path) => println!("replaced {}", relative(root, &path)),
+            Outcome::Kept(path) => bail!(
+                "`{}` already holds a solution, pass `--force` to replace it",
+                relative(root, &path)
+            ),
+        }
+    }
+    Ok(())
+}
+
+fn relative(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
@@ -0,0 +1,554 @@
+//! Downloads puzzle inputs from adventofcode.com and submits answers to it.
+//!
+//! The site is only contacted with a session token, read from the
+//! `AOC_SESSION` environment variable or from `.aoc_session` at the root of the
+//! repository. `AOC_URL` points the client at another server, such as a local
+//! stand-in.
+
+use std::cell::Cell;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, bail, Context, Error, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::input::PLACEHOLDER;
+use crate::solution::Part;
+
+/// Year of the calendar these solutions belong to.
+pub const YEAR: u16 = 2024;
+
+const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// The site asks automated clients to identify themselves.
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_AUTHORS"),
+    ")"
+);
+
+/// Written at the top of `submissions.toml` every time it is saved.
+const HEADER: &str = "\
+# Every answer submitted with `cargo run --bin aoc -- submit`, and the site's reply.
+# Consulted before submitting, so a known wrong answer is never sent twice.
+
+";
+
+/// Path of `.aoc_session`, holding the session token, at the root of the
+/// repository.
+pub fn session_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(".aoc_session")
+}
+
+/// Path of `submissions.toml` at the root of the repository.
+pub fn log_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("submissions.toml")
+}
+
+/// An HTTP client for one session, waiting `min_interval` between two
+/// requests so that a loop over the calendar does not hammer the site.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    min_interval: Duration,
+    last_request: Cell<Option<Instant>>,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            min_interval: Duration::from_secs(5),
+            last_request: Cell::new(None),
+        }
+    }
+
+    /// A client for `AOC_URL`, or the real site, using the session token
+    /// from `AOC_SESSION` or `.aoc_session`.
+    pub fn from_env() -> Result<Self, Error> {
+        let session = match std::env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => {
+                let path = session_path();
+                fs::read_to_string(&path).map_err(|_| {
+                    anyhow!(
+                        "no session token, set `AOC_SESSION` or save the `session` cookie of \
+                         adventofcode.com in `{}`",
+                        path.display()
+                    )
+                })?
+            }
+        };
+        let session = session.trim();
+        let session = session.strip_prefix("session=").unwrap_or(session);
+        if session.is_empty() {
+            bail!("the session token is empty");
+        }
+
+        let base_url = std::env::var("AOC_URL").unwrap_or_else(|_| DEFAULT_URL.to_string());
+        Ok(Self::new(base_url, session))
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Downloads the personal puzzle input of `day`.
+    pub fn fetch_input(&self, day: u8) -> Result<String, Error> {
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        let response = self.send(self.agent.get(&url), None)?;
+        response
+            .into_string()
+            .with_context(|| format!("failed to read the input of day {day:02}"))
+    }
+
+    /// Downloads the description of `day`, which holds the second part only
+    /// once the first one is solved.
+    pub fn fetch_page(&self, day: u8) -> Result<String, Error> {
+        let url = format!("{}/{YEAR}/day/{day}", self.base_url);
+        let response = self.send(self.agent.get(&url), None)?;
+        response
+            .into_string()
+            .with_context(|| format!("failed to read the description of day {day:02}"))
+    }
+
+    /// Submits `answer` to a part of `day` and reads the verdict off the page
+    /// the site replies with.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Reply, Error> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        let level = part.number().to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        let response = self.send(self.agent.post(&url), Some(&form))?;
+        let page = response
+            .into_string()
+            .with_context(|| format!("failed to read the reply to day {day:02} part {part}"))?;
+        Reply::from_page(&page)
+    }
+
+    fn send(
+        &self,
+        request: ureq::Request,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<ureq::Response, Error> {
+        self.throttle();
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let url = request.url().to_string();
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+
+        match response {
+            Ok(response) => Ok(response),
+            Err(ureq::Error::Status(404, _)) => {
+                bail!("`{url}` was not found, is the day unlocked?")
+            }
+            Err(ureq::Error::Status(code @ (400 | 500), _)) => {
+                bail!("`{url}` answered {code}, the session token is probably wrong or expired")
+            }
+            Err(ureq::Error::Status(code, _)) => bail!("`{url}` answered {code}"),
+            Err(err) => Err(err).with_context(|| format!("failed to reach `{url}`")),
+        }
+    }
+
+    fn throttle(&self) {
+        if let Some(last) = self.last_request.get() {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        self.last_request.set(Some(Instant::now()));
+    }
+}
+
+/// What the site thought of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+    /// The previous answer was too recent, so this one was not checked.
+    #[serde(skip)]
+    TooSoon,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "right answer",
+            Verdict::TooHigh => "wrong, too high",
+            Verdict::TooLow => "wrong, too low",
+            Verdict::Wrong => "wrong",
+            Verdict::AlreadySolved => "already solved",
+            Verdict::TooSoon => "not checked, answered too recently",
+        })
+    }
+}
+
+/// The site's reply to a submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reply {
+    pub verdict: Verdict,
+    /// How long the site refuses further answers.
+    pub wait: Option<Duration>,
+}
+
+impl Reply {
+    /// Reads the `<article>` of the page returned after a submission.
+    pub fn from_page(page: &str) -> Result<Self, Error> {
+        static WAIT: OnceLock<Regex> = OnceLock::new();
+        let wait = WAIT.get_or_init(|| {
+            Regex::new(
+                r"(?:You have (?:(\d+)m )?(\d+)s left to wait)|(?:wait (one|\d+) minutes? before)",
+            )
+            .unwrap()
+        });
+
+        let article = page
+            .split_once("<article")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(page, |(article, _)| article);
+
+        let verdict = if article.contains("That's the right answer") {
+            Verdict::Correct
+        } else if article.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else if article.contains("You gave an answer too recently") {
+            Verdict::TooSoon
+        } else if article.contains("That's not the right answer") {
+            if article.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if article.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else {
+            bail!("could not make sense of the reply to the submission");
+        };
+
+        let wait = wait.captures(article).map(|captures| {
+            let number = |i| {
+                captures
+                    .get(i)
+                    .map_or(0, |m| m.as_str().parse().unwrap_or(1))
+            };
+            match captures.get(3) {
+                Some(minutes) if minutes.as_str() == "one" => Duration::from_secs(60),
+                Some(_) => Duration::from_secs(60 * number(3)),
+                None => Duration::from_secs(60 * number(1) + number(2)),
+            }
+        });
+
+        Ok(Self { verdict, wait })
+    }
+}
+
+fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs())
+}
+
+/// Every submitted answer with its verdict, and when the site accepts the
+/// next one.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerLog {
+    /// Unix time before which the site refuses answers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait_until: Option<u64>,
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Submission {
+    day: u8,
+    part: u8,
+    answer: String,
+    verdict: Verdict,
+    /// Unix time of the submission.
+    at: u64,
+}
+
+impl AnswerLog {
+    /// Reads the log stored at `path`, a missing file holding none.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read `{}`", path.display()))
+            }
+        };
+
+        toml::from_str(&text)
+            .with_context(|| format!("`{}` is not a valid answer log", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let text = format!("{HEADER}{}", toml::to_string(self)?);
+        fs::write(path, text).with_context(|| format!("failed to write `{}`", path.display()))
+    }
+
+    /// What the site would say about `answer`, as far as earlier submissions
+    /// tell: the same answer, any answer other than a correct one, or a number
+    /// beyond one that was too high or too low.
+    pub fn known(&self, day: u8, part: Part, answer: &str) -> Option<Verdict> {
+        let part = part.number();
+        let earlier: Vec<&Submission> = self
+            .submissions
+            .iter()
+            .filter(|submission| submission.day == day && submission.part == part)
+            .collect();
+
+        if let Some(same) = earlier
+            .iter()
+            .find(|submission| submission.answer == answer)
+        {
+            return Some(same.verdict);
+        }
+        if earlier
+            .iter()
+            .any(|submission| submission.verdict == Verdict::Correct)
+        {
+            return Some(Verdict::Wrong);
+        }
+
+        let value: i64 = answer.parse().ok()?;
+        earlier.iter().find_map(|submission| {
+            let bound: i64 = submission.answer.parse().ok()?;
+            match submission.verdict {
+                Verdict::TooHigh if value >= bound => Some(Verdict::TooHigh),
+                Verdict::TooLow if value <= bound => Some(Verdict::TooLow),
+                _ => None,
+            }
+        })
+    }
+
+    /// How long until the site accepts answers again, if it asked to wait.
+    pub fn wait_left(&self) -> Option<Duration> {
+        let left = self.wait_until?.checked_sub(unix_time())?;
+        (left > 0).then(|| Duration::from_secs(left))
+    }
+
+    /// Stores the reply to a submission. Answers sent too soon were not
+    /// judged, so only their waiting time is kept.
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, reply: Reply) {
+        let now = unix_time();
+        self.wait_until = reply.wait.map(|wait| now + wait.as_secs());
+        if reply.verdict != Verdict::TooSoon {
+            self.submissions.push(Submission {
+                day,
+                part: part.number(),
+                answer: answer.to_string(),
+                verdict: reply.verdict,
+                at: now,
+            });
+        }
+    }
+}
+
+/// Saves the personal input of `day` to `path`, unless a file other than the
+/// template placeholder is already there and `force` is not set. Returns
+/// whether the input was downloaded.
+pub fn download(client: &Client, day: u8, path: &Path, force: bool) -> Result<bool, Error> {
+    if !force {
+        match fs::read_to_string(path) {
+            Ok(existing) if existing.trim() != PLACEHOLDER && !existing.trim().is_empty() => {
+                return Ok(false)
+            }
+            _ => {}
+        }
+    }
+
+    let input = client.fetch_input(day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create `{}`", parent.display()))?;
+    }
+    fs::write(path, input).with_context(|| format!("failed to write `{}`", path.display()))?;
+    Ok(true)
+}
+
+/// Submits `answer` unless `log` already knows its verdict or the site asked
+/// to wait, and logs the reply. Returns the verdict and whether the site was
+/// contacted.
+pub fn submit(
+    client: &Client,
+    log: &mut AnswerLog,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<(Verdict, bool), Error> {
+    if let Some(verdict) = log.known(day, part, answer) {
+        return Ok((verdict, false));
+    }
+    if let Some(left) = log.wait_left() {
+        bail!(
+            "the site asked to wait before answering again, try in {}s",
+            left.as_secs()
+        );
+    }
+
+    let reply = client.submit(day, part, answer)?;
+    log.record(day, part, answer, reply);
+    Ok((reply.verdict, true))
+}
+
+/// Answers the requests a test expects with `responses`, given as status and
+/// body, returning the address to reach it and the requests it received.
+#[cfg(test)]
+fn serve(responses: Vec<(u16, &'static str)>) -> (String, thread::JoinHandle<Vec<String>>) {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let server = thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|(status, body)| {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut body_in = vec![0; length];
+                reader.read_exact(&mut body_in).unwrap();
+                request.push_str(&String::from_utf8(body_in).unwrap());
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} Whatever\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+                request
+            })
+            .collect()
+    });
+
+    (url, server)
+}
+
+#[test]
+fn downloads_inputs_with_the_session_cookie() {
+    let (url, server) = serve(vec![(200, "3   4\n4   3\n"), (404, "Not Found")]);
+    let client = Client::new(url, "secret").with_min_interval(Duration::ZERO);
+
+    let dir = std::env::temp_dir().join(format!("aoc-client-{}", std::process::id()));
+    let path = dir.join("day_01.txt");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(&path, format!("{PLACEHOLDER}\n")).unwrap();
+
+    assert!(download(&client, 1, &path, false).unwrap());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+    // A real input is kept without contacting the site
+    assert!(!download(&client, 1, &path, false).unwrap());
+
+    let err = client.fetch_input(25).unwrap_err();
+    assert!(err.to_string().contains("is the day unlocked?"));
+
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("GET /2024/day/1/input "));
+    assert!(requests[0].contains("session=secret"));
+    assert!(requests[1].starts_with("GET /2024/day/25/input "));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn submits_answers_and_remembers_verdicts() {
+    let too_high = "<main><article><p>That's not the right answer; your answer is too high. \
+                    Please wait one minute before trying again.</p></article></main>";
+    let right = "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+    let (url, server) = serve(vec![(200, too_high), (200, right)]);
+    let client = Client::new(url, "secret").with_min_interval(Duration::ZERO);
+    let mut log = AnswerLog::default();
+
+    assert_eq!(
+        submit(&client, &mut log, 7, Part::Two, "500").unwrap(),
+        (Verdict::TooHigh, true)
+    );
+    assert!(log.wait_left().is_some());
+    // Larger answers are known to be too high, any other has to wait
+    assert_eq!(
+        submit(&client, &mut log, 7, Part::Two, "600").unwrap(),
+        (Verdict::TooHigh, false)
+    );
+    assert!(submit(&client, &mut log, 7, Part::Two, "400").is_err());
+
+    log.wait_until = None;
+    assert_eq!(
+        submit(&client, &mut log, 7, Part::Two, "400").unwrap(),
+        (Verdict::Correct, true)
+    );
+    assert_eq!(log.known(7, Part::Two, "401"), Some(Verdict::Wrong));
+    assert_eq!(log.known(7, Part::One, "401"), None);
+
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("POST /2024/day/7/answer "));
+    assert!(requests[0].ends_with("level=2&answer=500"));
+
+    let text = toml::to_string(&log).unwrap();
+    assert_eq!(toml::from_str::<AnswerLog>(&text).unwrap(), log);
+}
+
+#[test]
+fn reads_replies() {
+    let reply = |page| Reply::from_page(page).unwrap();
+
+    assert_eq!(
+        reply("<article><p>You gave an answer too recently. You have 1m 5s left to wait.</p></article>"),
+        Reply {
+            verdict: Verdict::TooSoon,
+            wait: Some(Duration::from_secs(65)),
+        }
+    );
+    assert_eq!(
+        reply("<article><p>That's not the right answer; your answer is too low. Please wait 5 minutes before trying again.</p></article>"),
+        Reply {
+            verdict: Verdict::TooLow,
+            wait: Some(Duration::from_secs(300)),
+        }
+    );
+    assert_eq!(
+        reply("<article><p>You don't seem to be solving the right level.</p></article>").verdict,
+        Verdict::AlreadySolved
+    );
+    assert!(Reply::from_page("<html>Log in</html>").is_err());
+}
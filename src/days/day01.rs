@@ -0,0 +1,176 @@
+use anyhow::{Error, Result};
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::generate::Rng;
+use crate::parse::{self, ParseError};
+use crate::solution::Solution;
+
+fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), Error> {
+    let mut left: Vec<i32> = Vec::new();
+    let mut right: Vec<i32> = Vec::new();
+
+    for line in input.lines() {
+        let mut iter = line.split_whitespace();
+        let (Some(first), Some(second), None) = (iter.next(), iter.next(), iter.next()) else {
+            return Err(ParseError::new(input, line, "expected two location IDs").into());
+        };
+        left.push(parse::field(input, first, "a location ID")?);
+        right.push(parse::field(input, second, "a location ID")?);
+    }
+
+    Ok((left, right))
+}
+
+fn solve_part_1((left, right): &(Vec<i32>, Vec<i32>)) -> Result<String, Error> {
+    let mut left = left.clone();
+    let mut right = right.clone();
+
+    left.sort();
+    right.sort();
+
+    let solution: i32 = left
+        .iter()
+        .zip(right.iter())
+        .map(|(l, r)| (l - r).abs())
+        .sum();
+
+    Ok(solution.to_string())
+}
+
+fn solve_part_2((left, right): &(Vec<i32>, Vec<i32>)) -> Result<String, Error> {
+    let mut right_list_frequency = HashMap::default();
+
+    right
+        .iter()
+        .for_each(|&n| *right_list_frequency.entry(n).or_default() += 1);
+
+    let mut solution: i32 = 0;
+
+    for &l in left {
+        solution += right_list_frequency.get(&l).unwrap_or(&0) * l;
+    }
+    Ok(solution.to_string())
+}
+
+/// `size` lines of two location IDs, a fifth of the right ones copied from
+/// the left list so the similarity score is not zero.
+fn generate_input(size: usize, rng: &mut Rng) -> String {
+    let left: Vec<i64> = (0..size).map(|_| rng.range(10_000..=99_999)).collect();
+    let mut input = String::new();
+    for &id in &left {
+        let right = if rng.chance(0.2) {
+            left[rng.below(size as u64) as usize]
+        } else {
+            rng.range(10_000..=99_999)
+        };
+        input.push_str(&format!("{id}   {right}\n"));
+    }
+    input
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+}
+
+// Test as playground to see how to parse correctly the input file day1.txt
+#[cfg(test)]
+#[test]
+fn test_input() {
+    const SAMPLE_INPUT_1: &str = "12 34\n56 78\n90 12";
+
+    let lines: Vec<(i32, i32)> = SAMPLE_INPUT_1
+        .lines()
+        .map(|line| {
+            let mut iter = line.split_whitespace();
+            let first = iter.next().unwrap().parse().unwrap();
+            let second = iter.next().unwrap().parse().unwrap();
+            (first, second)
+        })
+        .collect();
+
+    let (mut left, mut right): (Vec<i32>, Vec<i32>) = lines.iter().cloned().unzip();
+    // sort left and right vectors from smallest to largest
+    left.sort();
+    right.sort();
+    //print them sorted
+    println!("{:?}", left);
+    println!("{:?}", right);
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day01::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_1(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day01::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_2(&input).unwrap(), example.answer);
+    }
+}
+
+/// The two lists side by side, as the puzzle writes them.
+#[cfg(test)]
+fn render(left: &[i32], right: &[i32]) -> String {
+    left.iter()
+        .zip(right)
+        .map(|(a, b)| format!("{a}   {b}\n"))
+        .collect()
+}
+
+#[cfg(test)]
+fn lists() -> impl proptest::strategy::Strategy<Value = (Vec<i32>, Vec<i32>)> {
+    use proptest::strategy::Strategy;
+
+    proptest::collection::vec((0..100_000, 0..100_000), 1..50)
+        .prop_map(|pairs| pairs.into_iter().unzip())
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_lists(lists in lists()) {
+        let input = render(&lists.0, &lists.1);
+        proptest::prop_assert_eq!(parse_input(&input).unwrap(), lists);
+    }
+
+    #[test]
+    fn rejects_corrupted_lists(
+        (left, right) in lists(),
+        line: proptest::sample::Index,
+        column: proptest::sample::Index,
+    ) {
+        let input = render(&left, &right);
+        let line = line.index(input.lines().count());
+        parse::check_corrupted(&input, line, column, parse_input)?;
+    }
+}
@@ -0,0 +1,192 @@
+use anyhow::{Error, Result};
+
+use crate::generate::Rng;
+use crate::parse::{self, ParseError};
+use crate::solution::Solution;
+
+fn is_safe_report(levels: &[i32]) -> bool {
+    let mut is_increasing = true;
+    let mut is_decreasing = true;
+
+    for i in 1..levels.len() {
+        let diff = levels[i] - levels[i - 1];
+
+        if !(1..=3).contains(&diff) {
+            is_increasing = false;
+        }
+
+        let rev_diff = levels[i - 1] - levels[i];
+        if !(1..=3).contains(&rev_diff) {
+            is_decreasing = false;
+        }
+
+        if !is_increasing && !is_decreasing {
+            return false;
+        }
+    }
+
+    is_increasing || is_decreasing
+}
+
+fn is_safe_with_dampener(levels: &[i32]) -> bool {
+    if is_safe_report(levels) {
+        return true;
+    }
+
+    for i in 0..levels.len() {
+        let mut temp_levels = levels.to_vec();
+        temp_levels.remove(i);
+        if is_safe_report(&temp_levels) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, Error> {
+    let parsed_input = parse::lines(input, |report| {
+        let levels: Vec<i32> = parse::words(input, report, "a level")?;
+        if levels.is_empty() {
+            return Err(ParseError::new(
+                input,
+                report,
+                "expected at least one level",
+            ));
+        }
+        Ok(levels)
+    })?;
+
+    Ok(parsed_input)
+}
+
+fn solve_part_1(parsed_input: &[Vec<i32>]) -> Result<String, Error> {
+    let solution = parsed_input
+        .iter()
+        .filter(|report| is_safe_report(report))
+        .count();
+
+    Ok(solution.to_string())
+}
+
+fn solve_part_2(parsed_input: &[Vec<i32>]) -> Result<String, Error> {
+    let solution = parsed_input
+        .iter()
+        .filter(|report| is_safe_with_dampener(report))
+        .count();
+
+    Ok(solution.to_string())
+}
+
+/// `size` reports of 5 to 8 levels, each going steadily up or down except for
+/// a level that breaks the rules now and then, so that safe reports, reports
+/// saved by the dampener and unsafe ones all show up.
+fn generate_input(size: usize, rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let direction = if rng.chance(0.5) { 1 } else { -1 };
+        let mut level = rng.range(30..=70);
+        let mut report = Vec::new();
+        for _ in 0..rng.range(5..=8) {
+            report.push(level.to_string());
+            level += if rng.chance(0.1) {
+                rng.range(-4..=4)
+            } else {
+                direction * rng.range(1..=3)
+            };
+        }
+        input.push_str(&report.join(" "));
+        input.push('\n');
+    }
+    input
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day02::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_1(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day02::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_2(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn rejects_empty_reports() {
+    let err = parse_input("7 6 4\n\n1 2 7\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 1: expected at least one level"
+    );
+}
+
+/// One report per line, levels separated by spaces.
+#[cfg(test)]
+fn render(reports: &[Vec<i32>]) -> String {
+    reports
+        .iter()
+        .map(|levels| {
+            let levels: Vec<String> = levels.iter().map(i32::to_string).collect();
+            levels.join(" ") + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+fn reports() -> impl proptest::strategy::Strategy<Value = Vec<Vec<i32>>> {
+    proptest::collection::vec(proptest::collection::vec(1..100, 1..10), 1..50)
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_reports(reports in reports()) {
+        proptest::prop_assert_eq!(parse_input(&render(&reports)).unwrap(), reports);
+    }
+
+    #[test]
+    fn rejects_corrupted_reports(
+        reports in reports(),
+        line: proptest::sample::Index,
+        column: proptest::sample::Index,
+    ) {
+        let input = render(&reports);
+        let line = line.index(input.lines().count());
+        parse::check_corrupted(&input, line, column, parse_input)?;
+    }
+}
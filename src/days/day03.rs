@@ -0,0 +1,134 @@
+use anyhow::{Error, Result};
+use regex::Regex;
+
+use crate::parse::{self, ParseError};
+use crate::solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction>, Error> {
+    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)")?;
+
+    let instructions = re
+        .captures_iter(input)
+        .map(|cap| match &cap[0] {
+            "do()" => Ok(Instruction::Do),
+            "don't()" => Ok(Instruction::Dont),
+            _ => Ok(Instruction::Mul(
+                parse::field(input, &cap[1], "a number")?,
+                parse::field(input, &cap[2], "a number")?,
+            )),
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok(instructions)
+}
+
+fn solve_part_1(instructions: &[Instruction]) -> Result<String, Error> {
+    let solution = instructions
+        .iter()
+        .fold(0, |acc, instruction| match instruction {
+            Instruction::Mul(a, b) => acc + a * b,
+            _ => acc,
+        });
+
+    Ok(solution.to_string())
+}
+
+fn solve_part_2(instructions: &[Instruction]) -> Result<String, Error> {
+    //Only the most recent do() or don't() instruction applies
+    let mut enabled = true;
+    let mut solution = 0;
+    for instruction in instructions {
+        match instruction {
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+            Instruction::Mul(a, b) if enabled => solution += a * b,
+            Instruction::Mul(..) => (),
+        }
+    }
+
+    Ok(solution.to_string())
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input)
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day03::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_1(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day03::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_2(&input).unwrap(), example.answer);
+    }
+}
+
+/// The instructions with junk around them, like the corrupted memory of the
+/// puzzle. Any text is valid memory, so day 03 has no rejection property.
+#[cfg(test)]
+fn render(memory: &[(&str, Instruction)]) -> String {
+    memory
+        .iter()
+        .map(|(junk, instruction)| match instruction {
+            Instruction::Mul(a, b) => format!("{junk}mul({a},{b})"),
+            Instruction::Do => format!("{junk}do()"),
+            Instruction::Dont => format!("{junk}don't()"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+fn memory() -> impl proptest::strategy::Strategy<Value = Vec<(&'static str, Instruction)>> {
+    use proptest::strategy::{Just, Strategy};
+
+    let junk = proptest::sample::select(vec!["", "x", "%&", "mul[3,7]!", "don't", "(1,2)", " "]);
+    let instruction = proptest::prop_oneof![
+        (0..1000, 0..1000).prop_map(|(a, b)| Instruction::Mul(a, b)),
+        Just(Instruction::Do),
+        Just(Instruction::Dont),
+    ];
+    proptest::collection::vec((junk, instruction), 0..30)
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_memory(memory in memory()) {
+        let instructions: Vec<Instruction> = memory.iter().map(|&(_, instruction)| instruction).collect();
+        proptest::prop_assert_eq!(parse_input(&render(&memory)).unwrap(), instructions);
+    }
+}
@@ -0,0 +1,154 @@
+use anyhow::{bail, Error, Result};
+
+use crate::direction::Direction8;
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::solution::Solution;
+
+const KEYWORD: &str = "XMAS";
+
+fn parse_input(input: &str) -> Result<Grid<char>, Error> {
+    Grid::parse_with(input, |c| match c {
+        'X' | 'M' | 'A' | 'S' | '.' => Ok(c),
+        _ => bail!("expected a letter of `XMAS` or `.`, got `{c}`"),
+    })
+}
+
+fn solve_part_1(grid: &Grid<char>) -> Result<String, Error> {
+    let mut count = 0;
+    for start in grid.points() {
+        for direction in Direction8::ALL {
+            if is_valid_direction(grid, start, direction.offset()) {
+                count += 1
+            }
+        }
+    }
+
+    Ok(count.to_string())
+}
+
+fn is_valid_direction(grid: &Grid<char>, start: Point, direction: Point) -> bool {
+    let mut point = start;
+
+    for char in KEYWORD.chars() {
+        // Bound checking happens in `Grid::get`
+        if grid.get(point) != Some(&char) {
+            return false;
+        }
+        point += direction;
+    }
+
+    true
+}
+
+//
+// 0 1 2 3
+// 1 M A S
+// 2 S A M
+// 3 M S S
+
+fn solve_part_2(grid: &Grid<char>) -> Result<String, Error> {
+    // crosses on the border are rejected by the bound checks of `Grid::get`
+    let count = grid
+        .iter()
+        .filter(|&(center, &cell)| cell == 'A' && find_xmas_pattern(grid, center))
+        .count();
+
+    Ok(count.to_string())
+}
+
+// when we are in the center of the cross that is an 'A' we need to check the other 4 DIRECTIONS
+// to see if we make a cross with the key MAS. So we have fours posible combinations in the
+// diagonal directions
+// MS and MS, SM and SM, MM and SM, SM and MM
+fn find_xmas_pattern(grid: &Grid<char>, center: Point) -> bool {
+    // Corner patterns in clockwise order
+    let corner_patterns = [
+        ['M', 'S', 'S', 'M'],
+        ['S', 'M', 'M', 'S'],
+        ['M', 'M', 'S', 'S'],
+        ['S', 'S', 'M', 'M'],
+    ];
+
+    // loop clowise over the corner patters if one is matched return true
+    for pattern in corner_patterns.iter() {
+        if is_valid_corner_pattern(grid, center, pattern) {
+            return true;
+        }
+    }
+    false
+}
+
+fn is_valid_corner_pattern(grid: &Grid<char>, center: Point, pattern: &[char; 4]) -> bool {
+    for (&char, corner) in pattern.iter().zip(Direction8::DIAGONAL) {
+        if grid.get(center + corner.offset()) != Some(&char) {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input)
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day04::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_1(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day04::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_2(&input).unwrap(), example.answer);
+    }
+}
+
+#[cfg(test)]
+fn letters() -> impl proptest::strategy::Strategy<Value = Grid<char>> {
+    crate::grid::grids(proptest::sample::select(vec!['X', 'M', 'A', 'S']))
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_word_searches(letters in letters()) {
+        proptest::prop_assert_eq!(parse_input(&letters.to_string()).unwrap(), letters);
+    }
+
+    #[test]
+    fn rejects_corrupted_word_searches(
+        letters in letters(),
+        line: proptest::sample::Index,
+        column: proptest::sample::Index,
+    ) {
+        let line = line.index(letters.height());
+        crate::parse::check_corrupted(&letters.to_string(), line, column, parse_input)?;
+    }
+}
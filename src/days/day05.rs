@@ -0,0 +1,201 @@
+use anyhow::{Error, Result};
+use std::collections::HashMap;
+
+use crate::parse;
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct PrintQueue {
+    ordering_rules: HashMap<i32, Vec<i32>>,
+    pages: Vec<Vec<i32>>,
+}
+
+fn parse_input(input: &str) -> Result<PrintQueue, Error> {
+    let mut ordering_rules: HashMap<i32, Vec<i32>> = HashMap::new();
+    let [rules, updates] = parse::split_sections(input, ["the rules", "the updates"])?;
+
+    for rule in rules.lines() {
+        let (from, to) = parse::pair(input, rule, "|", "a rule like `47|53`")?;
+        let from = parse::field(input, from, "a page number")?;
+        let to = parse::field(input, to, "a page number")?;
+
+        ordering_rules.entry(from).or_default().push(to);
+    }
+    let pages = parse::lines(updates, |update| {
+        parse::records(input, update, ',', "a page number")
+    })?;
+
+    Ok(PrintQueue {
+        ordering_rules,
+        pages,
+    })
+}
+
+fn solve_part_1(queue: &PrintQueue) -> Result<String, Error> {
+    let PrintQueue {
+        ordering_rules,
+        pages,
+    } = queue;
+
+    // add up the middle page number from those correctly-ordered updates
+    let sum: i32 = pages
+        .iter()
+        .filter(|page| correct_order(page, ordering_rules))
+        .map(|page| get_middle_page_number(page))
+        .sum();
+
+    Ok(sum.to_string())
+}
+
+fn correct_order(page: &[i32], rules: &HashMap<i32, Vec<i32>>) -> bool {
+    let mut index_map = HashMap::new();
+    for (idx, &p) in page.iter().enumerate() {
+        index_map.insert(p, idx);
+    }
+
+    for (&from, tos) in rules {
+        if let Some(&from_idx) = index_map.get(&from) {
+            for &to in tos {
+                if let Some(&to_idx) = index_map.get(&to) {
+                    if from_idx >= to_idx {
+                        return false; // Ordering rule violated
+                    }
+                }
+            }
+        }
+    }
+
+    true
+}
+fn get_middle_page_number(page: &[i32]) -> i32 {
+    page[page.len() / 2]
+}
+
+fn solve_part_2(queue: &PrintQueue) -> Result<String, Error> {
+    let PrintQueue {
+        ordering_rules,
+        pages,
+    } = queue;
+
+    let sum: i32 = pages
+        .iter()
+        .filter(|page| !correct_order(page, ordering_rules))
+        .map(|page| order_page(page, ordering_rules))
+        .map(|page| get_middle_page_number(&page))
+        .sum();
+
+    Ok(sum.to_string())
+}
+
+fn order_page(page: &[i32], rules: &HashMap<i32, Vec<i32>>) -> Vec<i32> {
+    let mut page_ordered = page.to_vec();
+    page_ordered.sort_by(|a, b| {
+        if rules.get(a).is_some_and(|v| v.contains(b)) {
+            std::cmp::Ordering::Less
+        } else if rules.get(b).is_some_and(|v| v.contains(a)) {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    });
+    page_ordered
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = PrintQueue;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input)
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day05::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_1(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day05::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_2(&input).unwrap(), example.answer);
+    }
+}
+
+/// The rules and updates as the puzzle writes them.
+#[cfg(test)]
+fn render(rules: &[(i32, i32)], updates: &[Vec<i32>]) -> String {
+    let rules: String = rules
+        .iter()
+        .map(|(from, to)| format!("{from}|{to}\n"))
+        .collect();
+    let updates: String = updates
+        .iter()
+        .map(|update| {
+            let pages: Vec<String> = update.iter().map(i32::to_string).collect();
+            pages.join(",") + "\n"
+        })
+        .collect();
+    format!("{rules}\n{updates}")
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_queues(
+        rules in proptest::collection::vec((10..100, 10..100), 0..20),
+        updates in proptest::collection::vec(proptest::collection::vec(10..100, 1..10), 1..10),
+    ) {
+        let queue = parse_input(&render(&rules, &updates)).unwrap();
+
+        let mut expected: HashMap<i32, Vec<i32>> = HashMap::new();
+        for &(from, to) in &rules {
+            expected.entry(from).or_default().push(to);
+        }
+        proptest::prop_assert_eq!(queue.ordering_rules, expected);
+        proptest::prop_assert_eq!(queue.pages, updates);
+    }
+
+    #[test]
+    fn rejects_corrupted_queues(
+        rules in proptest::collection::vec((10..100, 10..100), 1..20),
+        updates in proptest::collection::vec(proptest::collection::vec(10..100, 1..10), 1..10),
+        line: proptest::sample::Index,
+        column: proptest::sample::Index,
+    ) {
+        let input = render(&rules, &updates);
+        let line = line.index(input.lines().count());
+        parse::check_corrupted(&input, line, column, parse_input)?;
+    }
+}
+
+#[test]
+fn rejects_misplaced_sections() {
+    let err = parse_input("47|53\n\n75,47\n\n61,53\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 4, column 1: expected a single blank line between the rules and the updates"
+    );
+    assert!(parse_input("47|53\n75,47\n").is_err());
+    assert!(parse_input("47|53\n61|13\n").is_err());
+}
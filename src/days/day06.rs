@@ -0,0 +1,354 @@
+// use std::collections::{HashMap, HashSet};
+use anyhow::{bail, Error, Result};
+use rustc_hash::FxHashSet as HashSet;
+
+use crate::direction::Direction;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::point::Point;
+use crate::solution::Solution;
+use crate::stepper::{Overlays, Simulation};
+use crate::visualize::{Frame, Mark, NoVisualizer, Visualizer};
+
+//NOTES:
+// '^' indicated the guard current position is facing up from the perspective of the map
+// obstructions are represented by '#'
+// Guards patrol with the following rules:
+//      If there is something directly in front of you, turn right 90 degrees.
+//      Otherwise, take a step forward.
+// count how many distinct positions the guard visits
+// The input is an assci map as a grid of '.' and '#' characters and the guard '^'
+
+#[derive(Debug, Clone)]
+pub struct Guard {
+    position: Point,
+    direction: Direction,
+    grid: Grid<char>,
+}
+
+impl Guard {
+    pub fn new(grid: Grid<char>) -> Self {
+        let position = grid.find(&'^').expect("the map has a guard");
+        Self {
+            position,
+            direction: Direction::Up,
+            grid,
+        }
+    }
+
+    fn rotate_right(&mut self) {
+        self.direction = self.direction.turn_right();
+    }
+
+    pub fn walk(&mut self, visualizer: &mut dyn Visualizer) -> usize {
+        let mut visited = HashSet::default();
+        visited.insert(self.position);
+
+        while let Some((next, cell)) = self.next_step() {
+            if cell == '#' {
+                self.rotate_right();
+                continue;
+            }
+            //move to next position
+            self.position = next;
+            visited.insert(self.position);
+
+            if visualizer.enabled() {
+                let caption = format!("{} positions visited", visited.len());
+                visualizer.frame(self.frame(caption, visited.iter().copied()));
+            }
+        }
+        visited.len()
+    }
+
+    /// The map with `found` highlighted and the guard on top of it.
+    fn frame(&self, caption: String, found: impl IntoIterator<Item = Point>) -> Frame {
+        Frame::new(caption, self.grid.clone())
+            .mark(found, Mark::Found)
+            .mark([self.position], Mark::Active)
+    }
+
+    /// The position in front of the guard and what it holds, or `None` once
+    /// the guard is about to leave the map.
+    fn next_step(&self) -> Option<(Point, char)> {
+        let next = self.position + self.direction.offset();
+        self.grid.get(next).map(|&cell| (next, cell))
+    }
+
+    /// 1. Instead of trying to detect loops during a single walk, we now test each possible position
+    /// 2. For each position, we simulate placing an obstruction and check if it creates a true loop
+    /// 3. A true loop is detected when we revisit a position with the same direction we had before
+    /// 4. We maintain the guard's original state by resetting after each test
+    pub fn walk_in_loop(&mut self, visualizer: &mut dyn Visualizer) -> usize {
+        let mut obstacles = HashSet::default();
+
+        while let Some((next, cell)) = self.next_step() {
+            if cell == '#' {
+                self.rotate_right();
+                continue;
+            }
+
+            // No obstruction can go where the guard starts
+            if cell != '^' && !obstacles.contains(&next) {
+                let mut grid_with_obstacles = self.grid.clone();
+                grid_with_obstacles[next] = '#';
+                let mut new_guard = Self::new(grid_with_obstacles);
+                if new_guard.detect_loop() {
+                    obstacles.insert(next);
+                }
+            }
+
+            //move to next position
+            self.position = next;
+
+            if visualizer.enabled() {
+                let caption = format!("{} obstructions make a loop", obstacles.len());
+                visualizer.frame(self.frame(caption, obstacles.iter().copied()));
+            }
+        }
+
+        obstacles.len()
+    }
+
+    fn detect_loop(&mut self) -> bool {
+        let mut visited = HashSet::default();
+        let mut state = (self.position, self.direction);
+
+        loop {
+            if !visited.insert(state) {
+                return true; // Found a loop
+            }
+
+            match self.next_step() {
+                None => return false, // Reached edge of grid
+                Some((_, '#')) => self.rotate_right(),
+                Some((next, _)) => self.position = next,
+            }
+            state = (self.position, self.direction);
+        }
+    }
+}
+
+/// The walk of part 1 one move at a time, for `aoc step`: a tick either
+/// turns the guard or moves it one cell. The walk ends when the guard leaves
+/// the map, or when it gets back where it was facing the same way, since it
+/// then walks in a loop forever.
+#[derive(Clone)]
+pub struct Patrol {
+    guard: Guard,
+    visited: HashSet<Point>,
+    states: HashSet<(Point, Direction)>,
+}
+
+impl Patrol {
+    pub fn new(guard: Guard) -> Self {
+        let visited = HashSet::from_iter([guard.position]);
+        Self {
+            guard,
+            visited,
+            states: HashSet::default(),
+        }
+    }
+}
+
+impl Simulation for Patrol {
+    fn tick(&mut self) -> bool {
+        if !self
+            .states
+            .insert((self.guard.position, self.guard.direction))
+        {
+            return false;
+        }
+        match self.guard.next_step() {
+            None => return false,
+            Some((_, '#')) => self.guard.rotate_right(),
+            Some((next, _)) => {
+                self.guard.position = next;
+                self.visited.insert(next);
+            }
+        }
+        true
+    }
+
+    fn frame(&self, overlays: Overlays) -> Frame {
+        let guard = &self.guard;
+        let mut grid = guard.grid.clone();
+        if overlays.direction {
+            grid[guard.position] = guard.direction.arrow();
+        }
+
+        let visited = self.visited.iter().copied();
+        let frame = Frame::new("Guard patrol", grid);
+        let frame = if overlays.visited {
+            frame.mark(visited, Mark::Found)
+        } else {
+            frame
+        };
+        frame.mark([guard.position], Mark::Active)
+    }
+
+    fn counters(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("visited", self.visited.len().to_string()),
+            ("position", format!("{:?}", self.guard.position)),
+            ("facing", format!("{:?}", self.guard.direction)),
+        ]
+    }
+}
+
+fn parse_input(input: &str) -> Result<Guard, Error> {
+    // from input load the grid a 2d.
+    let grid = Grid::parse_with(input, |c| match c {
+        '.' | '#' | '^' => Ok(c),
+        _ => bail!("expected `.`, an obstruction `#` or the guard `^`, got `{c}`"),
+    })?;
+    if grid.find(&'^').is_none() {
+        let first_line = input.lines().next().unwrap_or_default();
+        return Err(ParseError::new(input, first_line, "expected a guard `^` on the map").into());
+    }
+    Ok(Guard::new(grid))
+}
+
+fn solve_part_1(guard: &Guard, visualizer: &mut dyn Visualizer) -> Result<String, Error> {
+    let mut guard = guard.clone();
+    let solution = guard.walk(visualizer);
+
+    Ok(solution.to_string())
+}
+
+fn solve_part_2(guard: &Guard, visualizer: &mut dyn Visualizer) -> Result<String, Error> {
+    let mut guard = guard.clone();
+    let solution = guard.walk_in_loop(visualizer);
+
+    Ok(solution.to_string())
+}
+
+/// A `size` by `size` map with an obstruction on about 3% of the cells and
+/// the guard anywhere, drawn again until the guard walks out of it.
+fn generate_input(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(2);
+    loop {
+        let mut grid = Grid::from_fn(size, size, |_| if rng.chance(0.03) { '#' } else { '.' });
+        let start = Point::new(rng.below(size as u64) as i32, rng.below(size as u64) as i32);
+        grid[start] = '^';
+        if !Guard::new(grid.clone()).detect_loop() {
+            return grid.to_string();
+        }
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Guard;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input, &mut NoVisualizer)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input, &mut NoVisualizer)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+
+    fn visualize_part_1(
+        input: &Self::Input,
+        visualizer: &mut dyn Visualizer,
+    ) -> Result<String, Error> {
+        solve_part_1(input, visualizer)
+    }
+
+    fn visualize_part_2(
+        input: &Self::Input,
+        visualizer: &mut dyn Visualizer,
+    ) -> Result<String, Error> {
+        solve_part_2(input, visualizer)
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day06::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(
+            solve_part_1(&input, &mut NoVisualizer).unwrap(),
+            example.answer
+        );
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day06::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(
+            solve_part_2(&input, &mut NoVisualizer).unwrap(),
+            example.answer
+        );
+    }
+}
+
+#[test]
+fn patrol_stops_when_walking_in_a_loop() {
+    let guard = parse_input(".#...\n....#\n#^...\n...#.\n").unwrap();
+    let mut patrol = Patrol::new(guard);
+    let ticks = (0..100).take_while(|_| patrol.tick()).count();
+    assert_eq!((ticks, patrol.visited.len()), (10, 6));
+}
+
+#[test]
+fn rejects_maps_without_a_guard() {
+    let err = parse_input("....\n.#..\n....\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 1: expected a guard `^` on the map"
+    );
+}
+
+/// Maps with obstructions anywhere and the guard on one cell.
+#[cfg(test)]
+fn maps() -> impl proptest::strategy::Strategy<Value = Grid<char>> {
+    use proptest::strategy::Strategy;
+
+    let cells = crate::grid::grids(proptest::sample::select(vec!['.', '.', '#']));
+    (cells, proptest::arbitrary::any::<proptest::sample::Index>()).prop_map(|(mut grid, guard)| {
+        let (width, height) = (grid.width(), grid.height());
+        grid[Point::from_index(guard.index(width * height), width)] = '^';
+        grid
+    })
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_maps(map in maps()) {
+        let guard = parse_input(&map.to_string()).unwrap();
+        proptest::prop_assert_eq!(Some(guard.position), map.find(&'^'));
+        proptest::prop_assert_eq!(guard.grid, map);
+    }
+
+    #[test]
+    fn rejects_corrupted_maps(
+        map in maps(),
+        line: proptest::sample::Index,
+        column: proptest::sample::Index,
+    ) {
+        let line = line.index(map.height());
+        crate::parse::check_corrupted(&map.to_string(), line, column, parse_input)?;
+    }
+}
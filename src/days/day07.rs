@@ -0,0 +1,287 @@
+use anyhow::{Error, Result};
+
+use crate::generate::Rng;
+use crate::parse::{self, ParseError};
+use crate::solution::{Part, Solution, Variant};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Equation {
+    target: i64,
+    numbers: Vec<i64>,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Equation>, Error> {
+    let parsed_input = parse::lines(input, |line| {
+        let (target, numbers) = parse::pair(input, line, ":", "an equation like `190: 10 19`")?;
+        let target = parse::field(input, target.trim(), "a test value")?;
+        let numbers: Vec<i64> = parse::words(input, numbers, "a number")?;
+        if numbers.is_empty() {
+            let message = "expected at least one number after the test value";
+            return Err(ParseError::new(input, &line[line.len()..], message));
+        }
+        Ok(Equation { target, numbers })
+    })?;
+
+    Ok(parsed_input)
+}
+
+fn solve_part_1(parsed_input: &[Equation]) -> Result<String, Error> {
+    let total_sum: i64 = parsed_input
+        .iter()
+        .filter(|eq| is_valid(&eq.numbers, eq.target, false))
+        .map(|eq| eq.target)
+        .sum();
+    Ok(total_sum.to_string())
+}
+
+/// `concatenation` enables the `||` operator introduced in part 2
+fn is_valid(numbers: &[i64], target: i64, concatenation: bool) -> bool {
+    evaluate_equation(numbers, 0, numbers[0], target, concatenation)
+}
+
+/// Works back from the target instead, undoing the last operation first,
+/// which prunes most branches early. Always allows `||`.
+fn evaluate_equation_optimal(numbers: &[i64], index: usize, target: i64) -> bool {
+    // Base case: if we're at the first number
+    if index == 0 {
+        return numbers[0] == target;
+    }
+
+    let current_num = numbers[index];
+
+    // Try addition: if R + an = T, then R = T - an
+    let sub_result = target - current_num;
+    if evaluate_equation_optimal(numbers, index - 1, sub_result) {
+        return true;
+    }
+
+    // Try multiplication: if R × an = T, then R = T/an (if T is divisible by an)
+    if current_num != 0 && target % current_num == 0 {
+        let div_result = target / current_num;
+        if evaluate_equation_optimal(numbers, index - 1, div_result) {
+            return true;
+        }
+    }
+
+    // Try concatenation: if R || an = T, then T must end with an
+    let current_str = current_num.to_string();
+    let target_str = target.to_string();
+    if target_str.ends_with(&current_str) {
+        if let Some(remaining_str) = target_str.strip_suffix(&current_str) {
+            if let Ok(remaining) = remaining_str.parse::<i64>() {
+                if evaluate_equation_optimal(numbers, index - 1, remaining) {
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
+}
+
+fn evaluate_equation(
+    numbers: &[i64],
+    index: usize,
+    current_result: i64,
+    target: i64,
+    concatenation: bool,
+) -> bool {
+    if index == numbers.len() - 1 {
+        return current_result == target;
+    }
+
+    if evaluate_equation(
+        numbers,
+        index + 1,
+        current_result + numbers[index + 1],
+        target,
+        concatenation,
+    ) {
+        return true;
+    }
+
+    if evaluate_equation(
+        numbers,
+        index + 1,
+        current_result * numbers[index + 1],
+        target,
+        concatenation,
+    ) {
+        return true;
+    }
+
+    if !concatenation {
+        return false;
+    }
+
+    let concatenated_value = format!("{}{}", current_result, numbers[index + 1])
+        .parse::<i64>()
+        .unwrap();
+    if evaluate_equation(
+        numbers,
+        index + 1,
+        concatenated_value,
+        target,
+        concatenation,
+    ) {
+        return true; //they added a third operations which is || combines the digits from its left and right inputs into a single number
+    }
+
+    false
+}
+
+fn solve_part_2(parsed_input: &[Equation]) -> Result<String, Error> {
+    let total_sum: i64 = parsed_input
+        .iter()
+        .filter(|eq| is_valid(&eq.numbers, eq.target, true))
+        .map(|eq| eq.target)
+        .sum();
+    Ok(total_sum.to_string())
+}
+
+fn solve_part_2_backwards(parsed_input: &[Equation]) -> Result<String, Error> {
+    let total_sum: i64 = parsed_input
+        .iter()
+        .filter(|eq| evaluate_equation_optimal(&eq.numbers, eq.numbers.len() - 1, eq.target))
+        .map(|eq| eq.target)
+        .sum();
+    Ok(total_sum.to_string())
+}
+
+/// `size` equations of 2 to 8 numbers below 100, half of which can be made
+/// true with `+`, `*` and `||`. Results stay far below `i64::MAX`, even with
+/// every number concatenated.
+fn generate_input(size: usize, rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let numbers: Vec<i64> = (0..rng.range(2..=8)).map(|_| rng.range(1..=99)).collect();
+        let target = if rng.chance(0.5) {
+            numbers[1..]
+                .iter()
+                .fold(numbers[0], |result, &n| match rng.below(3) {
+                    0 => result + n,
+                    1 => result * n,
+                    _ => format!("{result}{n}").parse().unwrap(),
+                })
+        } else {
+            rng.range(1..=10_000_000)
+        };
+
+        let numbers: Vec<String> = numbers.iter().map(i64::to_string).collect();
+        input.push_str(&format!("{target}: {}\n", numbers.join(" ")));
+    }
+    input
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input)
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![Variant {
+            name: "backwards",
+            part: Part::Two,
+            solve: |input| solve_part_2_backwards(input),
+        }]
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day07::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_1(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day07::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_2(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn variants_agree_on_generated_inputs() {
+    use crate::differential::{self, DiffOptions};
+
+    let disagreement = differential::search(&Day07, DiffOptions::default()).unwrap();
+    assert!(disagreement.is_none(), "{disagreement:?}");
+}
+
+#[test]
+fn rejects_equations_without_numbers() {
+    let err = parse_input("190: 10 19\n5:\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 3: expected at least one number after the test value"
+    );
+}
+
+/// One equation per line, as the puzzle writes them.
+#[cfg(test)]
+fn render(equations: &[Equation]) -> String {
+    equations
+        .iter()
+        .map(|equation| {
+            let numbers: Vec<String> = equation.numbers.iter().map(i64::to_string).collect();
+            format!("{}: {}\n", equation.target, numbers.join(" "))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+fn equations() -> impl proptest::strategy::Strategy<Value = Vec<Equation>> {
+    use proptest::strategy::Strategy;
+
+    let equation = (
+        0..1_000_000_000i64,
+        proptest::collection::vec(1..1000i64, 1..8),
+    )
+        .prop_map(|(target, numbers)| Equation { target, numbers });
+    proptest::collection::vec(equation, 1..30)
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_equations(equations in equations()) {
+        proptest::prop_assert_eq!(parse_input(&render(&equations)).unwrap(), equations);
+    }
+
+    #[test]
+    fn rejects_corrupted_equations(
+        equations in equations(),
+        line: proptest::sample::Index,
+        column: proptest::sample::Index,
+    ) {
+        let input = render(&equations);
+        let line = line.index(input.lines().count());
+        parse::check_corrupted(&input, line, column, parse_input)?;
+    }
+}
@@ -0,0 +1,237 @@
+use anyhow::{bail, Error, Result};
+use std::collections::{HashMap, HashSet};
+
+use crate::grid::Grid;
+use crate::parse;
+use crate::point::Point;
+use crate::solution::Solution;
+use crate::topology::{Bounded, Topology};
+use crate::visualize::{Frame, Mark, NoVisualizer, Visualizer};
+
+#[derive(Debug)]
+pub struct AntennaMap {
+    antennas: HashMap<char, Vec<Point>>,
+    bounds: Bounded,
+}
+
+fn parse_input(input: &str) -> Result<AntennaMap, Error> {
+    let grid = parse::grid(input, input, |c| match c {
+        '.' => Ok(c),
+        _ if c.is_ascii_alphanumeric() => Ok(c),
+        _ => bail!("expected an antenna frequency, a letter or a digit, or `.`, got `{c}`"),
+    })?;
+
+    let mut antennas: HashMap<char, Vec<Point>> = HashMap::new();
+    for (point, &c) in grid.iter() {
+        if c != '.' {
+            antennas.entry(c).or_default().push(point);
+        }
+    }
+
+    Ok(AntennaMap {
+        antennas,
+        bounds: grid.bounded(),
+    })
+}
+
+/// The map with the antinodes found so far, and the pair of antennas being
+/// looked at.
+fn frame(
+    map: &AntennaMap,
+    antinodes: &HashSet<Point>,
+    pair: Option<(Point, Point)>,
+    caption: String,
+) -> Frame {
+    let mut grid = Grid::from_fn(
+        map.bounds.width() as usize,
+        map.bounds.height() as usize,
+        |_| '.',
+    );
+    for point in antinodes {
+        grid[*point] = '#';
+    }
+    for (&frequency, positions) in &map.antennas {
+        for &point in positions {
+            grid[point] = frequency;
+        }
+    }
+
+    Frame::new(caption, grid)
+        .mark(antinodes.iter().copied(), Mark::Found)
+        .mark(pair.into_iter().flat_map(|(p, q)| [p, q]), Mark::Active)
+}
+
+fn solve_part_1(map: &AntennaMap, visualizer: &mut dyn Visualizer) -> Result<String, Error> {
+    let antennas = &map.antennas;
+    let bounds = map.bounds;
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    for (frequency, antenna_positions) in antennas.iter() {
+        for (i, &p) in antenna_positions.iter().enumerate() {
+            for (j, &q) in antenna_positions.iter().enumerate() {
+                if i != j {
+                    let diff = q - p;
+                    let antinode_1 = q + diff;
+                    let antinode_2 = p - diff;
+
+                    if bounds.contains(antinode_1) {
+                        antinodes.insert(antinode_1);
+                    }
+                    if bounds.contains(antinode_2) {
+                        antinodes.insert(antinode_2);
+                    }
+
+                    if visualizer.enabled() {
+                        let caption = format!("Antennas `{frequency}` at {p:?} and {q:?}");
+                        visualizer.frame(frame(map, &antinodes, Some((p, q)), caption));
+                    }
+                }
+            }
+        }
+    }
+
+    if visualizer.enabled() {
+        let caption = format!("{} antinodes", antinodes.len());
+        visualizer.frame(frame(map, &antinodes, None, caption));
+    }
+
+    Ok(antinodes.len().to_string())
+}
+
+fn solve_part_2(map: &AntennaMap, visualizer: &mut dyn Visualizer) -> Result<String, Error> {
+    let antennas = &map.antennas;
+    let bounds = map.bounds;
+
+    // Use HashSet to store unique antinode locations
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    for (frequency, antenna_positions) in antennas.iter() {
+        for (i, &p) in antenna_positions.iter().enumerate() {
+            for (j, &q) in antenna_positions.iter().enumerate() {
+                if i != j {
+                    // Every grid position on the line counts, including the
+                    // ones between the antennas, so step by the reduced offset
+                    let step = (q - p).reduced();
+
+                    for step in [step, -step] {
+                        let mut antinode = p;
+                        while bounds.contains(antinode) {
+                            antinodes.insert(antinode);
+                            antinode += step;
+                        }
+                    }
+
+                    if visualizer.enabled() {
+                        let caption = format!("Antennas `{frequency}` at {p:?} and {q:?}");
+                        visualizer.frame(frame(map, &antinodes, Some((p, q)), caption));
+                    }
+                }
+            }
+        }
+    }
+
+    if visualizer.enabled() {
+        let caption = format!("{} antinodes", antinodes.len());
+        visualizer.frame(frame(map, &antinodes, None, caption));
+    }
+
+    Ok(antinodes.len().to_string())
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = AntennaMap;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input, &mut NoVisualizer)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input, &mut NoVisualizer)
+    }
+
+    fn visualize_part_1(
+        input: &Self::Input,
+        visualizer: &mut dyn Visualizer,
+    ) -> Result<String, Error> {
+        solve_part_1(input, visualizer)
+    }
+
+    fn visualize_part_2(
+        input: &Self::Input,
+        visualizer: &mut dyn Visualizer,
+    ) -> Result<String, Error> {
+        solve_part_2(input, visualizer)
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day08::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(
+            solve_part_1(&input, &mut NoVisualizer).unwrap(),
+            example.answer
+        );
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day08::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(
+            solve_part_2(&input, &mut NoVisualizer).unwrap(),
+            example.answer
+        );
+    }
+}
+
+#[test]
+fn rejects_ragged_and_empty_maps() {
+    let err = parse_input("..a.\n.a\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 1: expected 4 columns like the first line, found 2"
+    );
+
+    let err = parse_input("..a.\n.#..\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 2: expected an antenna frequency, a letter or a digit, or `.`, got `#`"
+    );
+
+    let err = parse_input("").unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 1: the map is empty");
+}
+
+// `x` is a frequency like any other letter, so a corrupted map is still a
+// valid one and day 08 has no rejection property
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_maps(
+        map in crate::grid::grids(proptest::sample::select(vec!['.', '.', '.', 'a', 'A', '0'])),
+    ) {
+        let parsed = parse_input(&map.to_string()).unwrap();
+        proptest::prop_assert_eq!(parsed.bounds, map.bounded());
+
+        let mut antennas: HashMap<char, Vec<Point>> = HashMap::new();
+        for (point, &c) in map.iter().filter(|&(_, &c)| c != '.') {
+            antennas.entry(c).or_default().push(point);
+        }
+        proptest::prop_assert_eq!(parsed.antennas, antennas);
+    }
+}
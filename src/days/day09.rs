@@ -0,0 +1,226 @@
+use anyhow::{Error, Result};
+
+use crate::generate::Rng;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+//NOTE:
+//The disk map uses a dense format to represent the layout of files and free space
+//on the disk.
+//The digits alternate between indicating the length of a file and the length of free space.
+
+/// Expands the disk map into one entry per block: the file id, or -1 for free space
+fn parse_input(diskmap: &str) -> Result<Vec<i32>, Error> {
+    let mut extended_layout: Vec<i32> = Vec::new();
+    let mut id_number = 0;
+    for (i, c) in diskmap.char_indices() {
+        if c == '\n' {
+            continue;
+        }
+        let file = c.to_digit(10).ok_or_else(|| {
+            ParseError::new(diskmap, &diskmap[i..i + c.len_utf8()], "expected a digit")
+        })? as i32;
+        if i % 2 == 0 {
+            extended_layout.extend(std::iter::repeat_n(id_number, file as usize));
+            id_number += 1;
+        } else {
+            extended_layout.extend(std::iter::repeat_n(-1, file as usize));
+        }
+    }
+
+    Ok(extended_layout)
+}
+
+fn solve_part_1(layout: &[i32]) -> Result<String, Error> {
+    let mut extended_layout = layout.to_vec();
+
+    let mut left = 0;
+    // An empty disk has nothing to compact
+    let mut right = extended_layout.len().saturating_sub(1);
+
+    while left < right {
+        while left < right && extended_layout[left] != -1 {
+            left += 1;
+        }
+        while left < right && extended_layout[right] == -1 {
+            right -= 1;
+        }
+        // swap arr[i] to arr[j]
+        if left < right {
+            extended_layout.swap(left, right);
+            left += 1;
+            right -= 1;
+        }
+    }
+
+    // println!("Compacted layout: {:?}", extended_layout);
+
+    let mut output: u64 = 0;
+    for (i, &id) in extended_layout.iter().enumerate() {
+        if id < 0 {
+            continue;
+        }
+        let product = i as u64 * id as u64;
+        // dbg!(i, id, product);
+        output += product;
+    }
+
+    Ok(output.to_string())
+}
+
+fn solve_part_2(layout: &[i32]) -> Result<String, Error> {
+    let mut extended_layout = layout.to_vec();
+
+    let mut right = extended_layout.len();
+    while right > 0 {
+        right -= 1; // Move to a file
+
+        if extended_layout[right] != -1 {
+            let file_id = extended_layout[right];
+
+            // Compute file size correctly
+            let mut file_size = 1;
+            while right > 0 && extended_layout[right - 1] == file_id {
+                right -= 1;
+                file_size += 1;
+            }
+
+            let mut left = 0;
+            // Find first suitable free space
+            while left < right {
+                if extended_layout[left] == -1 {
+                    let mut free_space = 0;
+                    let mut tmp_right = left;
+
+                    while tmp_right < extended_layout.len() && extended_layout[tmp_right] == -1 {
+                        free_space += 1;
+                        tmp_right += 1;
+                    }
+
+                    // Move file if space is available
+                    if free_space >= file_size {
+                        // Clear old position and move in one pass
+                        for i in 0..file_size {
+                            extended_layout[left + i] = file_id;
+                            extended_layout[right + i] = -1;
+                        }
+
+                        // println!("Layout after moving: {:?}", extended_layout);
+                        break; // Stop searching after moving
+                    }
+                }
+                left += 1;
+            }
+        }
+    }
+
+    let mut output: u64 = 0;
+    for (i, &id) in extended_layout.iter().enumerate() {
+        if id < 0 {
+            continue;
+        }
+        let product = i as u64 * id as u64;
+        // dbg!(i, id, product);
+        output += product;
+    }
+
+    Ok(output.to_string())
+}
+
+/// A disk map of `size` digits (one more when even, so it ends with a file):
+/// files of 1 to 9 blocks and free spaces of 0 to 9 blocks in turn.
+fn generate_input(size: usize, rng: &mut Rng) -> String {
+    let mut input: String = (0..size.max(1) | 1)
+        .map(|i| {
+            let blocks = if i % 2 == 0 {
+                rng.range(1..=9)
+            } else {
+                rng.range(0..=9)
+            };
+            char::from_digit(blocks as u32, 10).unwrap()
+        })
+        .collect();
+    input.push('\n');
+    input
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day09::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_1(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day09::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_2(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn empty_disks_have_a_zero_checksum() {
+    let layout = parse_input("0\n").unwrap();
+    assert!(layout.is_empty());
+    assert_eq!(solve_part_1(&layout).unwrap(), "0");
+    assert_eq!(solve_part_2(&layout).unwrap(), "0");
+}
+
+/// The disk map as a single line of digits.
+#[cfg(test)]
+fn render(digits: &[u32]) -> String {
+    digits.iter().map(u32::to_string).collect::<String>() + "\n"
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_disk_maps(digits in proptest::collection::vec(0..10u32, 1..100)) {
+        // Files and free space alternate, each file numbered after the last
+        let mut layout = Vec::new();
+        for (i, &digit) in digits.iter().enumerate() {
+            let block = if i % 2 == 0 { (i / 2) as i32 } else { -1 };
+            layout.extend(std::iter::repeat_n(block, digit as usize));
+        }
+        proptest::prop_assert_eq!(parse_input(&render(&digits)).unwrap(), layout);
+    }
+
+    #[test]
+    fn rejects_corrupted_disk_maps(
+        digits in proptest::collection::vec(0..10u32, 1..100),
+        column: proptest::sample::Index,
+    ) {
+        crate::parse::check_corrupted(&render(&digits), 0, column, parse_input)?;
+    }
+}
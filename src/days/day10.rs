@@ -0,0 +1,170 @@
+use rayon::prelude::*;
+
+use anyhow::{Context, Error, Result};
+
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::search;
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct TopographicMap {
+    grid: Grid<u8>,
+    trailhead: Vec<Point>,
+}
+
+impl TopographicMap {
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let grid = Grid::parse_with(input, |c| {
+            c.to_digit(10)
+                .map(|height| height as u8)
+                .with_context(|| format!("expected a height from 0 to 9, got `{c}`"))
+        })?;
+
+        let trailhead = grid
+            .iter()
+            .filter(|&(_, &height)| height == 0)
+            .map(|(point, _)| point)
+            .collect();
+
+        Ok(Self { grid, trailhead })
+    }
+
+    /// The cells one step uphill from `point`.
+    fn uphill(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        let height = self.grid[point];
+        self.grid
+            .neighbours(point)
+            .filter(move |&(_, &next)| next == height + 1)
+            .map(|(next, _)| next)
+    }
+
+    /// The height 9 cells reachable from a starting position
+    fn hike(&self, starting_pos: Point) -> usize {
+        search::dfs(starting_pos, |&point| self.uphill(point))
+            .into_iter()
+            .filter(|&point| self.grid[point] == 9)
+            .count()
+    }
+
+    /// The number of distinct trails from a starting position to any height 9
+    /// cell. Every step climbs by one, so every trail to a cell is one of its
+    /// shortest paths.
+    fn hike_with_rating(&self, starting_pos: Point) -> usize {
+        let paths = search::bfs([starting_pos], |&point| self.uphill(point));
+        paths
+            .reached()
+            .iter()
+            .filter(|&&point| self.grid[point] == 9)
+            .map(|point| paths.count(point) as usize)
+            .sum()
+    }
+
+    /// Count the number of reachable height 9 cells for each trailhead
+    pub fn count_trailhead(&self) -> usize {
+        self.trailhead
+            .par_iter()
+            .map(|&trail| self.hike(trail))
+            .sum()
+    }
+    /// Count the number of reachable height 9 cells for each trailhead with different paths
+    pub fn count_trailhead_part_2(&self) -> usize {
+        self.trailhead
+            .par_iter()
+            .map(|&trail| self.hike_with_rating(trail))
+            .sum()
+    }
+}
+
+fn parse_input(input: &str) -> Result<TopographicMap, Error> {
+    TopographicMap::new(input)
+}
+
+fn solve_part_1(map: &TopographicMap) -> Result<String, Error> {
+    let count = map.count_trailhead();
+
+    Ok(count.to_string())
+}
+
+fn solve_part_2(map: &TopographicMap) -> Result<String, Error> {
+    let count = map.count_trailhead_part_2();
+
+    Ok(count.to_string())
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = TopographicMap;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input)
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day10::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_1(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day10::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_2(&input).unwrap(), example.answer);
+    }
+}
+
+/// The heights as rows of digits.
+#[cfg(test)]
+fn render(heights: &Grid<u8>) -> String {
+    heights
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|height| height.to_string())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_maps(heights in crate::grid::grids(0..10u8)) {
+        let map = parse_input(&render(&heights)).unwrap();
+        let trailheads = heights.iter().filter(|&(_, &height)| height == 0).count();
+        proptest::prop_assert_eq!(map.trailhead.len(), trailheads);
+        proptest::prop_assert_eq!(map.grid, heights);
+    }
+
+    #[test]
+    fn rejects_corrupted_maps(
+        heights in crate::grid::grids(0..10u8),
+        line: proptest::sample::Index,
+        column: proptest::sample::Index,
+    ) {
+        let input = render(&heights);
+        let line = line.index(input.lines().count());
+        crate::parse::check_corrupted(&input, line, column, parse_input)?;
+    }
+}
@@ -0,0 +1,152 @@
+use anyhow::{Error, Result};
+
+use crate::generate::Rng;
+use crate::memo::Memo;
+use crate::parse;
+use crate::solution::Solution;
+
+/// Stone counts by engraved number and blinks left.
+type Cache = Memo<(u64, usize), u64>;
+
+fn count(stone: u64, blinks: usize, cache: &Cache) -> u64 {
+    if blinks == 0 {
+        return 1;
+    }
+    cache.get((stone, blinks), |cache, _| match stone {
+        0 => count(1, blinks - 1, cache),
+        _ if stone.to_string().len().is_multiple_of(2) => {
+            let stone_str = stone.to_string();
+            let digit_count = stone_str.len();
+
+            let mid = digit_count / 2;
+            let left = stone_str[..mid].parse::<u64>().unwrap_or(0);
+            let right = stone_str[mid..].parse::<u64>().unwrap_or(0);
+            count(left, blinks - 1, cache) + count(right, blinks - 1, cache)
+        }
+        _ => count(stone * 2024, blinks - 1, cache),
+    })
+}
+
+fn count_after(stones: &[u64], blinks: usize, cache: &Cache) -> u64 {
+    stones
+        .iter()
+        .map(|&stone| count(stone, blinks, cache))
+        .sum()
+}
+
+const TIMES_PART_ONE: usize = 25;
+const TIMES_PART_TWO: usize = 75;
+
+/// The stones, along with the counts worked out so far: part 2 blinks 50
+/// more times than part 1, so it reuses every count part 1 found.
+#[derive(Debug)]
+pub struct Stones {
+    stones: Vec<u64>,
+    cache: Cache,
+}
+
+fn parse_input(input: &str) -> Result<Stones, Error> {
+    let stones = parse::words(input, input, "a stone number")?;
+
+    Ok(Stones {
+        stones,
+        cache: Memo::new(),
+    })
+}
+
+fn solve_part_1(stones: &Stones) -> Result<String, Error> {
+    let count_blinks = count_after(&stones.stones, TIMES_PART_ONE, &stones.cache);
+
+    Ok(count_blinks.to_string())
+}
+
+fn solve_part_2(stones: &Stones) -> Result<String, Error> {
+    let count_blinks = count_after(&stones.stones, TIMES_PART_TWO, &stones.cache);
+
+    Ok(count_blinks.to_string())
+}
+
+/// `size` stones engraved with numbers of up to seven digits.
+fn generate_input(size: usize, rng: &mut Rng) -> String {
+    let stones: Vec<String> = (0..size)
+        .map(|_| rng.range(0..=9_999_999).to_string())
+        .collect();
+    stones.join(" ") + "\n"
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Stones;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day11::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_1(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day11::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_2(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn parts_share_counts() {
+    let stones = parse_input("125 17").unwrap();
+    assert_eq!(solve_part_1(&stones).unwrap(), "55312");
+    let after_part_1 = stones.cache.stats();
+
+    // Every count of 25 blinks is one of 75 blinks, 50 blinks further down
+    solve_part_2(&stones).unwrap();
+    let stats = stones.cache.stats();
+    assert!(stats.hits > after_part_1.hits);
+    solve_part_1(&stones).unwrap();
+    assert_eq!(stones.cache.stats().misses, stats.misses);
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_stones(stones in proptest::collection::vec(proptest::num::u64::ANY, 1..30)) {
+        let input = stones.iter().map(u64::to_string).collect::<Vec<_>>().join(" ") + "\n";
+        proptest::prop_assert_eq!(parse_input(&input).unwrap().stones, stones);
+    }
+
+    #[test]
+    fn rejects_corrupted_stones(
+        stones in proptest::collection::vec(proptest::num::u64::ANY, 1..30),
+        column: proptest::sample::Index,
+    ) {
+        let input = stones.iter().map(u64::to_string).collect::<Vec<_>>().join(" ") + "\n";
+        parse::check_corrupted(&input, 0, column, parse_input)?;
+    }
+}
@@ -0,0 +1,178 @@
+use rustc_hash::FxHashSet as HashSet;
+
+use anyhow::{bail, Error, Result};
+
+use crate::direction::{Direction, Direction8};
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::solution::Solution;
+use crate::union_find;
+
+#[derive(Debug)]
+pub struct Garden {
+    plot: Grid<char>,
+}
+
+#[derive(Debug, Default)]
+struct Region {
+    area: u32,
+    perimeter: u32,
+    crop: Vec<Point>,
+}
+
+impl Region {
+    /// A polygon has as many sides as corners, so count corners for each crop
+    fn sides(&self) -> u32 {
+        let crop: HashSet<Point> = self.crop.iter().copied().collect();
+
+        let mut corners = 0;
+        for &plant in crop.iter() {
+            for corner in Direction8::DIAGONAL.map(Direction8::offset) {
+                let vertical = crop.contains(&(plant + Point::new(0, corner.y)));
+                let horizontal = crop.contains(&(plant + Point::new(corner.x, 0)));
+                let diagonal = crop.contains(&(plant + corner));
+
+                // outer corner: both neighbours are outside the region
+                // inner corner: both neighbours are inside but the diagonal is not
+                if (!vertical && !horizontal) || (vertical && horizontal && !diagonal) {
+                    corners += 1;
+                }
+            }
+        }
+        corners
+    }
+}
+
+impl Garden {
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let plot = Grid::parse_with(input, |c| {
+            if c.is_ascii_uppercase() {
+                Ok(c)
+            } else {
+                bail!("expected a plant, a capital letter, got `{c}`")
+            }
+        })?;
+        Ok(Self { plot })
+    }
+
+    pub fn fencing_price(&self) -> u32 {
+        let regions = self.get_regions();
+        regions
+            .iter()
+            .map(|region| region.area * region.perimeter)
+            .sum()
+    }
+
+    pub fn fencing_price_part_two(&self) -> u32 {
+        let regions = self.get_regions();
+        regions
+            .iter()
+            .map(|region| region.area * region.sides())
+            .sum()
+    }
+
+    fn get_regions(&self) -> Vec<Region> {
+        union_find::components(&self.plot, |a, b| a == b)
+            .cells
+            .into_iter()
+            .map(|crop| self.region(crop))
+            .collect()
+    }
+
+    fn region(&self, crop: Vec<Point>) -> Region {
+        let plant_type = self.plot[crop[0]];
+
+        // Out of bounds or a different plant type on a side adds to the perimeter
+        let perimeter = crop
+            .iter()
+            .flat_map(|&pos| Direction::ALL.map(|direction| pos + direction.offset()))
+            .filter(|&next| self.plot.get(next) != Some(&plant_type))
+            .count();
+
+        Region {
+            area: crop.len() as u32,
+            perimeter: perimeter as u32,
+            crop,
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Garden, Error> {
+    Garden::new(input)
+}
+
+fn solve_part_1(garden: &Garden) -> Result<String, Error> {
+    let total_price = garden.fencing_price();
+
+    Ok(total_price.to_string())
+}
+
+fn solve_part_2(garden: &Garden) -> Result<String, Error> {
+    let total_price = garden.fencing_price_part_two();
+
+    Ok(total_price.to_string())
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Garden;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input)
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day12::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_1(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day12::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_2(&input).unwrap(), example.answer);
+    }
+}
+
+#[cfg(test)]
+fn plots() -> impl proptest::strategy::Strategy<Value = Grid<char>> {
+    crate::grid::grids(proptest::sample::select(vec!['A', 'B', 'C']))
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_gardens(plots in plots()) {
+        proptest::prop_assert_eq!(parse_input(&plots.to_string()).unwrap().plot, plots);
+    }
+
+    #[test]
+    fn rejects_corrupted_gardens(
+        plots in plots(),
+        line: proptest::sample::Index,
+        column: proptest::sample::Index,
+    ) {
+        let line = line.index(plots.height());
+        crate::parse::check_corrupted(&plots.to_string(), line, column, parse_input)?;
+    }
+}
@@ -0,0 +1,212 @@
+use anyhow::{Error, Result};
+use regex::Regex;
+
+use crate::parse::{self, ParseError};
+use crate::solution::Solution;
+
+// Button A: X+94, Y+34
+// Button B: X+22, Y+67
+// Prize: X=8400, Y=5400
+//
+// point (0,0)
+// 94x + 22y = 8400
+// 34x + 67y = 5400
+
+//To avoid floating points precision i will solve this as diophantine equation
+
+const BUTTOM_A: i64 = 3;
+const BUTTOM_B: i64 = 1;
+
+const SCALE_PART_2: i64 = 10000000000000;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Equation {
+    eq1: (i64, i64, i64), //x_coef,y_coef,result
+    eq2: (i64, i64, i64),
+}
+
+impl Equation {
+    /// `offset` is added to both prize coordinates, part 2 uses `SCALE_PART_2`
+    fn solve(&self, offset: i64) -> Option<(i64, i64)> {
+        let (a1, b1, c1) = self.eq1;
+        let (a2, b2, c2) = self.eq2;
+        let (c1, c2) = (c1 + offset, c2 + offset);
+
+        // Solve the system:
+        // a1 * x + b1 * y = c1
+        // a2 * x + b2 * y = c2
+
+        // First, find the determinant of the system
+        let determinant = a1 * b2 - a2 * b1;
+
+        if determinant == 0 {
+            // The system is either inconsistent or has infinitely many solutions
+            return None;
+        }
+
+        // Find the solution using Cramer's rule
+        let x_numerator = c1 * b2 - c2 * b1;
+        let y_numerator = a1 * c2 - a2 * c1;
+
+        if x_numerator % determinant != 0 || y_numerator % determinant != 0 {
+            // No integer solutions exist
+            return None;
+        }
+
+        let x = x_numerator / determinant;
+        let y = y_numerator / determinant;
+
+        Some((x, y))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Equation>, Error> {
+    let mut equations = Vec::new();
+
+    // One regex per line, so a line that does not match is reported as is
+    // instead of being skipped
+    let button_a = Regex::new(r"^Button A: X\+(\d+), Y\+(\d+)$")?;
+    let button_b = Regex::new(r"^Button B: X\+(\d+), Y\+(\d+)$")?;
+    let prize = Regex::new(r"^Prize: X=(\d+), Y=(\d+)$")?;
+
+    for machine in input.trim_end().split("\n\n") {
+        let lines: Vec<&str> = machine.lines().collect();
+        let [a, b, p] = lines[..] else {
+            let message = "expected a claw machine of three lines, followed by a blank line";
+            return Err(ParseError::new(input, machine, message).into());
+        };
+
+        // Extract the captured groups and parse them into integers
+        let numbers = |re: &Regex, line: &str, example: &str| {
+            let caps = re
+                .captures(line)
+                .ok_or_else(|| ParseError::new(input, line, format!("expected `{example}`")))?;
+            let x = parse::field(input, caps.get(1).unwrap().as_str(), "a number")?;
+            let y = parse::field(input, caps.get(2).unwrap().as_str(), "a number")?;
+            Ok::<_, ParseError>((x, y))
+        };
+        let (x1, y1) = numbers(&button_a, a, "Button A: X+94, Y+34")?;
+        let (x2, y2) = numbers(&button_b, b, "Button B: X+22, Y+67")?;
+        let (prize_x, prize_y) = numbers(&prize, p, "Prize: X=8400, Y=5400")?;
+
+        equations.push(Equation {
+            eq1: (x1, x2, prize_x),
+            eq2: (y1, y2, prize_y),
+        });
+    }
+
+    Ok(equations)
+}
+
+fn solve_part_1(equations: &[Equation]) -> Result<String, Error> {
+    let total_tokens: i64 = equations
+        .iter()
+        .filter_map(|eq| eq.solve(0))
+        .map(|(x, y)| x * BUTTOM_A + y * BUTTOM_B)
+        .sum();
+
+    Ok(total_tokens.to_string())
+}
+
+fn solve_part_2(equations: &[Equation]) -> Result<String, Error> {
+    let total_tokens: i64 = equations
+        .iter()
+        .filter_map(|eq| eq.solve(SCALE_PART_2))
+        .map(|(x, y)| x * BUTTOM_A + y * BUTTOM_B)
+        .sum();
+
+    Ok(total_tokens.to_string())
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input)
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day13::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_1(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day13::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_2(&input).unwrap(), example.answer);
+    }
+}
+
+/// Claw machines as the puzzle writes them, from their buttons and prize.
+#[cfg(test)]
+fn render(machines: &[[(i64, i64); 3]]) -> String {
+    let machines: Vec<String> = machines
+        .iter()
+        .map(|[(ax, ay), (bx, by), (px, py)]| {
+            format!("Button A: X+{ax}, Y+{ay}\nButton B: X+{bx}, Y+{by}\nPrize: X={px}, Y={py}\n")
+        })
+        .collect();
+    machines.join("\n")
+}
+
+#[cfg(test)]
+fn machines() -> impl proptest::strategy::Strategy<Value = Vec<[(i64, i64); 3]>> {
+    use proptest::strategy::Strategy;
+
+    let button = (1..100i64, 1..100i64);
+    let prize = (0..100_000i64, 0..100_000i64);
+    proptest::collection::vec(
+        (button.clone(), button, prize).prop_map(|(a, b, p)| [a, b, p]),
+        1..10,
+    )
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_machines(machines in machines()) {
+        let equations = parse_input(&render(&machines)).unwrap();
+
+        let expected: Vec<Equation> = machines
+            .iter()
+            .map(|&[(ax, ay), (bx, by), (px, py)]| Equation {
+                eq1: (ax, bx, px),
+                eq2: (ay, by, py),
+            })
+            .collect();
+        proptest::prop_assert_eq!(equations, expected);
+    }
+
+    #[test]
+    fn rejects_corrupted_machines(
+        machines in machines(),
+        line: proptest::sample::Index,
+        column: proptest::sample::Index,
+    ) {
+        let input = render(&machines);
+        let line = line.index(input.lines().count());
+        parse::check_corrupted(&input, line, column, parse_input)?;
+    }
+}
@@ -0,0 +1,362 @@
+use anyhow::{bail, Error, Result};
+use std::cmp::Ordering::*;
+
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::point::Point;
+use crate::solution::Solution;
+use crate::stepper::{Overlays, Simulation};
+use crate::topology::{Topology, Toroidal};
+use crate::visualize::{Frame, NoVisualizer, Visualizer};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Robot {
+    position: Point,
+    velocity: Point,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Robot>, Error> {
+    // let bot_match = regex::Regex::new(r"p=<(\d+),(\d+)> v=<(\d+),(\d+)>").unwrap();
+    let bot_match = regex::Regex::new(r"^p\=(\d+),(\d+) v\=(-\d+|\d+),(-\d+|\d+)$")?;
+    let mut bots = Vec::new();
+    for line in input.trim_end().lines() {
+        let caps = bot_match
+            .captures(line)
+            .ok_or_else(|| ParseError::new(input, line, "expected a robot like `p=0,4 v=3,-3`"))?;
+        let number = |i| parse::field(input, &caps[i], "a number");
+        bots.push(Robot {
+            position: Point::new(number(1)?, number(2)?),
+            velocity: Point::new(number(3)?, number(4)?),
+        });
+    }
+    Ok(bots)
+}
+
+/// Fails if a robot starts outside `space`, the parser taking any room size.
+fn check_inside(bots: &[Robot], space: Toroidal) -> Result<(), Error> {
+    if let Some(bot) = bots.iter().find(|bot| !space.contains(bot.position)) {
+        let (width, height) = (space.width(), space.height());
+        bail!(
+            "a robot starts at {},{}, outside the {width}x{height} space",
+            bot.position.x,
+            bot.position.y
+        );
+    }
+    Ok(())
+}
+
+const WIDE: i32 = 101;
+const HEIGHT: i32 = 103;
+const TIMES: i32 = 100;
+
+/// Robots walking off an edge come back on the other side
+const SPACE: Toroidal = Toroidal::new(WIDE, HEIGHT);
+
+/// Moves every robot one second forwards in `space`, dropping those that
+/// leave it.
+fn step(bots: &mut Vec<Robot>, space: &impl Topology) {
+    bots.retain_mut(|bot| match space.advance(bot.position, bot.velocity, 1) {
+        Some(position) => {
+            bot.position = position;
+            true
+        }
+        None => false,
+    });
+}
+
+fn solve_part_1(bots: &[Robot]) -> Result<String, Error> {
+    check_inside(bots, SPACE)?;
+    Ok(safety_factor(bots, SPACE).to_string())
+}
+
+/// The sample robots move in a smaller 11x7 space, so the space is a parameter
+fn safety_factor(bots: &[Robot], space: Toroidal) -> i32 {
+    let mut quadrants = [0; 4];
+
+    let center_x = space.width() / 2;
+    let center_y = space.height() / 2;
+
+    bots.iter().for_each(|bot| {
+        let Some(Point { x, y }) = space.advance(bot.position, bot.velocity, TIMES) else {
+            return;
+        };
+
+        match (x.cmp(&center_x), y.cmp(&center_y)) {
+            (Less, Less) => quadrants[0] += 1,
+            (Less, Greater) => quadrants[1] += 1,
+            (Greater, Less) => quadrants[2] += 1,
+            (Greater, Greater) => quadrants[3] += 1,
+            _ => (),
+        }
+    });
+
+    // the solution is the product of all the quadrants
+    quadrants.iter().product()
+}
+
+fn solve_part_2(bots: &[Robot], visualizer: &mut dyn Visualizer) -> Result<String, Error> {
+    check_inside(bots, SPACE)?;
+    let mut bots = bots.to_vec();
+
+    // create a vector that works as map of the grid storing the amount of bot in each position throug the wide .
+    // use a loop that breaks when found a line of more than 10 lined robots
+    // positions repeat every WIDE * HEIGHT seconds, so give up after one full cycle
+    for seconds in 1..=WIDE * HEIGHT {
+        // Update positions of all robots
+        let mut grid = vec![vec![0; WIDE as usize]; HEIGHT as usize];
+
+        step(&mut bots, &SPACE);
+        for bot in &bots {
+            grid[bot.position.y as usize][bot.position.x as usize] += 1;
+        }
+
+        if visualizer.enabled() {
+            let picture = Grid::from_fn(WIDE as usize, HEIGHT as usize, |p| {
+                if grid[p.y as usize][p.x as usize] > 0 {
+                    '#'
+                } else {
+                    '.'
+                }
+            });
+            visualizer.frame(Frame::new(format!("After {seconds} seconds"), picture));
+        }
+
+        // If we found a line long enough, return the result
+        if grid.iter().any(|row| longest_line(row) >= 30) {
+            return Ok(seconds.to_string());
+        }
+    }
+
+    bail!("the robots never lined up in {} seconds", WIDE * HEIGHT)
+}
+
+/// The most robots standing next to each other in a row of robot counts.
+fn longest_line(row: &[u32]) -> usize {
+    let mut current_length = 0;
+    let mut max_length = 0;
+
+    for &robots in row.iter() {
+        if robots > 0 {
+            current_length += 1;
+        } else {
+            max_length = max_length.max(current_length);
+            current_length = 0;
+        }
+    }
+
+    // Check the final length (in case the line ends at the edge)
+    max_length.max(current_length)
+}
+
+/// The robots moving second by second, for `aoc step`. Positions repeat after
+/// `WIDE * HEIGHT` seconds, which ends the simulation. There is nothing
+/// visited nor any direction to draw, the overlays change nothing.
+#[derive(Clone)]
+pub struct Motion {
+    bots: Vec<Robot>,
+    seconds: i32,
+}
+
+impl Motion {
+    pub fn new(bots: &[Robot]) -> Result<Self, Error> {
+        check_inside(bots, SPACE)?;
+        Ok(Self {
+            bots: bots.to_vec(),
+            seconds: 0,
+        })
+    }
+
+    /// How many robots stand on each cell.
+    fn counts(&self) -> Vec<Vec<u32>> {
+        let mut grid = vec![vec![0; WIDE as usize]; HEIGHT as usize];
+        for bot in &self.bots {
+            grid[bot.position.y as usize][bot.position.x as usize] += 1;
+        }
+        grid
+    }
+}
+
+impl Simulation for Motion {
+    fn tick(&mut self) -> bool {
+        if self.seconds == WIDE * HEIGHT {
+            return false;
+        }
+        step(&mut self.bots, &SPACE);
+        self.seconds += 1;
+        true
+    }
+
+    fn frame(&self, _: Overlays) -> Frame {
+        let counts = self.counts();
+        let picture = Grid::from_fn(WIDE as usize, HEIGHT as usize, |p| {
+            match counts[p.y as usize][p.x as usize] {
+                0 => '.',
+                robots => char::from_digit(robots.min(9), 10).unwrap(),
+            }
+        });
+        Frame::new("Robots", picture)
+    }
+
+    fn counters(&self) -> Vec<(&'static str, String)> {
+        let longest = self.counts().iter().map(|row| longest_line(row)).max();
+        vec![
+            ("seconds", self.seconds.to_string()),
+            ("longest line", longest.unwrap_or_default().to_string()),
+        ]
+    }
+}
+
+/// `size` robots (at least 31), the first 31 of which stand in a row at some
+/// random second so that part 2 finds a tree.
+fn generate_input(size: usize, rng: &mut Rng) -> String {
+    const LINE: usize = 31;
+    let seconds = rng.range(1..=i64::from(WIDE * HEIGHT - 1)) as i32;
+    let row = rng.range(0..=i64::from(HEIGHT - 1)) as i32;
+    let first = rng.range(0..=i64::from(WIDE) - LINE as i64) as i32;
+
+    let mut input = String::new();
+    for i in 0..size.max(LINE) {
+        let velocity = Point::new(rng.range(-100..=100) as i32, rng.range(-100..=100) as i32);
+        let position = if i < LINE {
+            // Wind the clock back from the row the robot stands in at `seconds`
+            let end = Point::new(first + i as i32, row);
+            SPACE.advance(end, velocity, -seconds).unwrap()
+        } else {
+            Point::new(
+                rng.range(0..=i64::from(WIDE - 1)) as i32,
+                rng.range(0..=i64::from(HEIGHT - 1)) as i32,
+            )
+        };
+        input.push_str(&format!(
+            "p={},{} v={},{}\n",
+            position.x, position.y, velocity.x, velocity.y
+        ));
+    }
+    input
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input, &mut NoVisualizer)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+
+    fn visualize_part_2(
+        input: &Self::Input,
+        visualizer: &mut dyn Visualizer,
+    ) -> Result<String, Error> {
+        solve_part_2(input, visualizer)
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day14::DAY, Part::One) {
+        let bots = parse_input(&example.input).unwrap();
+        let answer = safety_factor(&bots, Toroidal::new(11, 7));
+        assert_eq!(answer.to_string(), example.answer);
+    }
+}
+
+#[test]
+fn robots_walk_off_a_bounded_space() {
+    use crate::topology::Bounded;
+
+    let mut bots = parse_input("p=0,4 v=3,-3\np=9,5 v=-3,-3\np=2,0 v=2,-1\n").unwrap();
+    step(&mut bots, &Bounded::new(11, 7));
+    // The last one walked off the top edge instead of coming back at the bottom
+    let positions: Vec<Point> = bots.iter().map(|bot| bot.position).collect();
+    assert_eq!(positions, [Point::new(3, 1), Point::new(6, 2)]);
+}
+
+// The puzzle has no sample for part 2: the sample robots never draw a tree,
+// so the search has to give up instead of looping forever
+#[test]
+fn gives_up_when_the_robots_never_line_up() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day14::DAY, Part::One) {
+        let bots = parse_input(&example.input).unwrap();
+        assert!(solve_part_2(&bots, &mut NoVisualizer).is_err());
+    }
+}
+
+#[cfg(test)]
+fn robots() -> impl proptest::strategy::Strategy<Value = Vec<Robot>> {
+    use proptest::strategy::Strategy;
+
+    let robot = (0..200, 0..200, -100..=100, -100..=100).prop_map(|(x, y, dx, dy)| Robot {
+        position: Point::new(x, y),
+        velocity: Point::new(dx, dy),
+    });
+    proptest::collection::vec(robot, 1..50)
+}
+
+/// The robots as the puzzle writes them.
+#[cfg(test)]
+fn render(bots: &[Robot]) -> String {
+    bots.iter()
+        .map(|bot| {
+            let (p, v) = (bot.position, bot.velocity);
+            format!("p={},{} v={},{}\n", p.x, p.y, v.x, v.y)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_robots(bots in robots()) {
+        proptest::prop_assert_eq!(parse_input(&render(&bots)).unwrap(), bots);
+    }
+
+    #[test]
+    fn rejects_corrupted_robots(
+        bots in robots(),
+        line: proptest::sample::Index,
+        column: proptest::sample::Index,
+    ) {
+        let input = render(&bots);
+        let line = line.index(input.lines().count());
+        parse::check_corrupted(&input, line, column, parse_input)?;
+    }
+}
+
+#[test]
+fn rejects_blank_lines_and_robots_outside() {
+    let err = parse_input("p=0,4 v=3,-3\n\np=6,3 v=-1,-3\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 1: expected a robot like `p=0,4 v=3,-3`"
+    );
+
+    // Any room size parses, only solving needs the robots in the real one
+    let bots = parse_input("p=0,4 v=3,-3\np=101,3 v=-1,-3\n").unwrap();
+    assert_eq!(bots[1].position, Point::new(101, 3));
+    assert_eq!(
+        solve_part_1(&bots).unwrap_err().to_string(),
+        "a robot starts at 101,3, outside the 101x103 space"
+    );
+    assert!(Motion::new(&bots).is_err());
+}
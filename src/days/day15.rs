@@ -0,0 +1,385 @@
+use anyhow::{bail, Error, Result};
+use rustc_hash::FxHashSet as HashSet;
+
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::point::Point;
+use crate::solution::{Part, Solution};
+use crate::stepper::{Overlays, Simulation};
+use crate::visualize::{Frame, Mark, NoVisualizer, Visualizer};
+
+#[derive(Clone)]
+struct Warehouse {
+    grid: Grid<char>,
+    lanternfish: Point,
+}
+
+impl Warehouse {
+    fn new(grid: Grid<char>) -> Self {
+        let position = grid.find(&'@').expect("the map has a robot");
+
+        Self {
+            grid,
+            lanternfish: position,
+        }
+    }
+
+    /// The second warehouse, where everything except the robot is twice as
+    /// wide.
+    fn widen(grid: &Grid<char>) -> Grid<char> {
+        let cells = grid
+            .iter()
+            .flat_map(|(_, &tile)| match tile {
+                'O' => ['[', ']'],
+                '@' => ['@', '.'],
+                tile => [tile, tile],
+            })
+            .collect();
+
+        Grid::new(grid.width() * 2, grid.height(), cells)
+    }
+
+    pub fn sum_of_all_boxes(&self) -> u32 {
+        let mut gps_sum = 0;
+        const GPS_MULTIPLIER: u32 = 100;
+
+        for (position, &tile) in self.grid.iter() {
+            if tile == 'O' || tile == '[' {
+                gps_sum += GPS_MULTIPLIER * position.y as u32 + position.x as u32;
+            }
+        }
+
+        gps_sum
+    }
+
+    /// The warehouse with the lanternfish highlighted.
+    fn frame(&self, caption: String) -> Frame {
+        Frame::new(caption, self.grid.clone()).mark([self.lanternfish], Mark::Active)
+    }
+
+    pub fn move_to(&mut self, direction: Direction) {
+        let step = direction.offset();
+
+        // Every tile pushed by the move, starting with the lanternfish itself.
+        // Tiles are collected row by row (or column by column), so moving
+        // them in reverse order never overwrites a tile that still has to move
+        let mut pushed = vec![self.lanternfish];
+        let mut index = 0;
+
+        while let Some(&position) = pushed.get(index) {
+            index += 1;
+            let next = position + step;
+
+            // Pushing a wide box vertically also pushes its other half
+            let other_half = match self.grid[next] {
+                '#' => return,
+                '[' if direction.is_vertical() => Some(next + Direction::Right.offset()),
+                ']' if direction.is_vertical() => Some(next + Direction::Left.offset()),
+                'O' | '[' | ']' => None,
+                _ => continue,
+            };
+
+            for tile in std::iter::once(next).chain(other_half) {
+                if !pushed.contains(&tile) {
+                    pushed.push(tile);
+                }
+            }
+        }
+
+        for &position in pushed.iter().rev() {
+            let new_position = position + step;
+            self.grid[new_position] = self.grid[position];
+            self.grid[position] = '.';
+        }
+
+        // Update the lanternfish position
+        self.lanternfish += step;
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    grid: Grid<char>,
+    moves: Vec<Direction>,
+}
+
+fn parse_input(input: &str) -> Result<Puzzle, Error> {
+    let [grid, moves] = parse::split_sections(input, ["the map", "the moves"])?;
+
+    let moves = moves
+        .char_indices()
+        .filter(|&(_, c)| !char::is_whitespace(c))
+        .map(|(i, c)| {
+            Direction::try_from(c)
+                .map_err(|err| ParseError::new(input, &moves[i..i + c.len_utf8()], err.to_string()))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let map = grid;
+    let grid = parse::grid(input, map, |c| match c {
+        '#' | '.' | 'O' | '@' => Ok(c),
+        _ => bail!("expected a wall `#`, a box `O`, the robot `@` or `.`, got `{c}`"),
+    })?;
+    // Moves never check the edge of the map, the walls around it stop them
+    let (right, bottom) = (grid.width() as i32 - 1, grid.height() as i32 - 1);
+    let gap = grid.iter().find(|&(point, &cell)| {
+        let edge = point.x == 0 || point.y == 0 || point.x == right || point.y == bottom;
+        edge && cell != '#'
+    });
+    if let Some((point, _)) = gap {
+        let line = map.lines().nth(point.y as usize).unwrap_or_default();
+        let x = point.x as usize;
+        let message = "expected a wall `#` all around the map";
+        return Err(ParseError::new(input, &line[x..x + 1], message).into());
+    }
+    if grid.find(&'@').is_none() {
+        let first_line = map.lines().next().unwrap_or_default();
+        return Err(ParseError::new(input, first_line, "expected a robot `@` on the map").into());
+    }
+
+    Ok(Puzzle { grid, moves })
+}
+
+/// The moves one by one, for `aoc step`.
+#[derive(Clone)]
+pub struct Moves {
+    warehouse: Warehouse,
+    moves: Vec<Direction>,
+    done: usize,
+    visited: HashSet<Point>,
+}
+
+impl Moves {
+    /// The moves in the warehouse of `part`, widened for part 2.
+    pub fn new(puzzle: &Puzzle, part: Part) -> Self {
+        let grid = match part {
+            Part::One => puzzle.grid.clone(),
+            Part::Two => Warehouse::widen(&puzzle.grid),
+        };
+        let warehouse = Warehouse::new(grid);
+        let visited = HashSet::from_iter([warehouse.lanternfish]);
+
+        Self {
+            warehouse,
+            moves: puzzle.moves.clone(),
+            done: 0,
+            visited,
+        }
+    }
+}
+
+impl Simulation for Moves {
+    fn tick(&mut self) -> bool {
+        let Some(&direction) = self.moves.get(self.done) else {
+            return false;
+        };
+        self.warehouse.move_to(direction);
+        self.visited.insert(self.warehouse.lanternfish);
+        self.done += 1;
+        true
+    }
+
+    fn frame(&self, overlays: Overlays) -> Frame {
+        let mut frame = self.warehouse.frame("Warehouse".to_string());
+        if overlays.visited {
+            // Under the lanternfish, which was marked first
+            let visited = self.visited.iter().map(|&point| (point, Mark::Found));
+            frame.marks.splice(0..0, visited);
+        }
+        if let (true, Some(next)) = (overlays.direction, self.moves.get(self.done)) {
+            frame.grid[self.warehouse.lanternfish] = next.arrow();
+        }
+        frame
+    }
+
+    fn counters(&self) -> Vec<(&'static str, String)> {
+        let next = self
+            .moves
+            .get(self.done)
+            .map_or("none".to_string(), |next| format!("{next:?}"));
+        vec![
+            ("moves", format!("{}/{}", self.done, self.moves.len())),
+            ("next", next),
+            ("GPS sum", self.warehouse.sum_of_all_boxes().to_string()),
+        ]
+    }
+}
+
+fn gps_sum_after_moves(
+    grid: Grid<char>,
+    moves: &[Direction],
+    visualizer: &mut dyn Visualizer,
+) -> u32 {
+    let mut warehouse = Warehouse::new(grid);
+    if visualizer.enabled() {
+        visualizer.frame(warehouse.frame(format!("Before the {} moves", moves.len())));
+    }
+
+    for (i, &direction) in moves.iter().enumerate() {
+        warehouse.move_to(direction);
+        if visualizer.enabled() {
+            let caption = format!("Move {} of {}: {direction:?}", i + 1, moves.len());
+            visualizer.frame(warehouse.frame(caption));
+        }
+    }
+
+    warehouse.sum_of_all_boxes()
+}
+
+fn solve_part_1(puzzle: &Puzzle, visualizer: &mut dyn Visualizer) -> Result<String, Error> {
+    Ok(gps_sum_after_moves(puzzle.grid.clone(), &puzzle.moves, visualizer).to_string())
+}
+
+fn solve_part_2(puzzle: &Puzzle, visualizer: &mut dyn Visualizer) -> Result<String, Error> {
+    let grid = Warehouse::widen(&puzzle.grid);
+    Ok(gps_sum_after_moves(grid, &puzzle.moves, visualizer).to_string())
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Puzzle;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input, &mut NoVisualizer)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input, &mut NoVisualizer)
+    }
+
+    fn visualize_part_1(
+        input: &Self::Input,
+        visualizer: &mut dyn Visualizer,
+    ) -> Result<String, Error> {
+        solve_part_1(input, visualizer)
+    }
+
+    fn visualize_part_2(
+        input: &Self::Input,
+        visualizer: &mut dyn Visualizer,
+    ) -> Result<String, Error> {
+        solve_part_2(input, visualizer)
+    }
+}
+
+#[test]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day15::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(
+            solve_part_1(&input, &mut NoVisualizer).unwrap(),
+            example.answer
+        );
+    }
+}
+
+#[test]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day15::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(
+            solve_part_2(&input, &mut NoVisualizer).unwrap(),
+            example.answer
+        );
+    }
+}
+
+#[test]
+fn rejects_maps_without_walls_around() {
+    let err = parse_input(".@.\n\n>>>\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 1: expected a wall `#` all around the map"
+    );
+
+    let err = parse_input("####\n#@O.\n####\n\n>>>\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 4: expected a wall `#` all around the map"
+    );
+}
+
+#[test]
+fn rejects_maps_without_a_robot() {
+    let err = parse_input("####\n#.O#\n####\n\n<>\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 1: expected a robot `@` on the map"
+    );
+}
+
+/// The map, a blank line and the moves twenty to a line.
+#[cfg(test)]
+fn render(puzzle: &Puzzle) -> String {
+    let moves: Vec<char> = puzzle
+        .moves
+        .iter()
+        .map(|direction| direction.arrow())
+        .collect();
+    let moves: Vec<String> = moves.chunks(20).map(|line| line.iter().collect()).collect();
+    format!("{}\n{}\n", puzzle.grid, moves.join("\n"))
+}
+
+#[cfg(test)]
+fn puzzles() -> impl proptest::strategy::Strategy<Value = Puzzle> {
+    use proptest::strategy::Strategy;
+
+    // The inside of a warehouse, walled in on every side
+    let cells = (1..10usize, 1..10usize).prop_flat_map(|(width, height)| {
+        let cells = proptest::collection::vec(
+            proptest::sample::select(vec!['#', '.', 'O']),
+            width * height,
+        );
+        (cells, proptest::arbitrary::any::<proptest::sample::Index>()).prop_map(
+            move |(mut cells, robot)| {
+                cells[robot.index(width * height)] = '@';
+                Grid::from_fn(width + 2, height + 2, |point| {
+                    let (x, y) = (point.x as usize, point.y as usize);
+                    if x == 0 || y == 0 || x > width || y > height {
+                        '#'
+                    } else {
+                        cells[(y - 1) * width + x - 1]
+                    }
+                })
+            },
+        )
+    });
+    let moves = proptest::collection::vec(proptest::sample::select(Direction::ALL.to_vec()), 1..60);
+    (cells, moves).prop_map(|(grid, moves)| Puzzle { grid, moves })
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn parses_rendered_puzzles(puzzle in puzzles()) {
+        let parsed = parse_input(&render(&puzzle)).unwrap();
+        proptest::prop_assert_eq!(&parsed, &puzzle);
+        // Any warehouse that parses can be solved
+        proptest::prop_assert!(solve_part_1(&parsed, &mut NoVisualizer).is_ok());
+        proptest::prop_assert!(solve_part_2(&parsed, &mut NoVisualizer).is_ok());
+    }
+
+    #[test]
+    fn rejects_corrupted_puzzles(
+        puzzle in puzzles(),
+        line: proptest::sample::Index,
+        column: proptest::sample::Index,
+    ) {
+        let input = render(&puzzle);
+        let line = line.index(input.lines().count());
+        parse::check_corrupted(&input, line, column, parse_input)?;
+    }
+}
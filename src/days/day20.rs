@@ -0,0 +1,58 @@
+use anyhow::{bail, Error, Result};
+
+use crate::solution::Solution;
+
+fn parse_input(input: &str) -> Result<Vec<String>, Error> {
+    Ok(input.lines().map(str::to_string).collect())
+}
+
+fn solve_part_1(input: &[String]) -> Result<String, Error> {
+    bail!("part 1 is not solved yet ({} lines of input)", input.len())
+}
+
+fn solve_part_2(input: &[String]) -> Result<String, Error> {
+    bail!("part 2 is not solved yet ({} lines of input)", input.len())
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input) -> Result<String, Error> {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Result<String, Error> {
+        solve_part_2(input)
+    }
+}
+
+#[test]
+#[ignore = "extract the examples with `cargo run --bin aoc -- examples 20`"]
+fn sample_part_1() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day20::DAY, Part::One) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_1(&input).unwrap(), example.answer);
+    }
+}
+
+#[test]
+#[ignore = "extract the examples with `cargo run --bin aoc -- examples 20`"]
+fn sample_part_2() {
+    use crate::examples;
+    use crate::solution::Part;
+
+    for example in examples::of(Day20::DAY, Part::Two) {
+        let input = parse_input(&example.input).unwrap();
+        assert_eq!(solve_part_2(&input).unwrap(), example.answer);
+    }
+}
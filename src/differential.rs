@@ -0,0 +1,249 @@
+//! Differential testing of the [`Variant`]s of a day: every variant solves
+//! the same generated inputs as the main solver, and the first input they
+//! disagree on is shrunk to the smallest one that still shows it.
+//!
+//! [`Variant`]: crate::solution::Variant
+
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use anyhow::{anyhow, bail, Error, Result};
+
+use crate::generate::Rng;
+use crate::solution::{DynSolution, Part};
+
+/// How many inputs to try, and how they are generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffOptions {
+    pub cases: usize,
+    pub size: usize,
+    /// Seed of the first input, the next ones counting up from it.
+    pub seed: u64,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self {
+            cases: 100,
+            size: 10,
+            seed: 0,
+        }
+    }
+}
+
+/// What a solver made of an input: an answer, or why there is none.
+pub type Outcome = Result<String, String>;
+
+/// An input on which the solvers of a part do not all give the same answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub part: Part,
+    pub input: String,
+    /// The main solver first, named `main`, then every variant.
+    pub outcomes: Vec<(&'static str, Outcome)>,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Part {} disagrees on:\n\n{}", self.part, self.input)?;
+        for (name, outcome) in &self.outcomes {
+            match outcome {
+                Ok(answer) => writeln!(f, "  {name}: {answer}")?,
+                Err(err) => writeln!(f, "  {name}: {err}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Solves `input` with every solver of each part that has variants, and
+/// returns the outcomes of the first part they disagree on.
+///
+/// Errors, panics included, all count as the same outcome: a variant may fail
+/// differently from the main solver, as long as both fail. `None` when the
+/// input does not parse.
+pub fn compare(solution: &dyn DynSolution, input: &str) -> Option<Disagreement> {
+    let parsed = solution.parse(input).ok()?;
+
+    [Part::One, Part::Two].into_iter().find_map(|part| {
+        let variants: Vec<&'static str> = solution
+            .variants()
+            .into_iter()
+            .filter(|&(of, _)| of == part)
+            .map(|(_, name)| name)
+            .collect();
+        if variants.is_empty() {
+            return None;
+        }
+
+        let mut outcomes = vec![("main", attempt(|| parsed.solve(part)))];
+        for name in variants {
+            outcomes.push((name, attempt(|| parsed.solve_variant(part, name))));
+        }
+
+        let agree = outcomes
+            .windows(2)
+            .all(|pair| match (&pair[0].1, &pair[1].1) {
+                (Ok(a), Ok(b)) => a == b,
+                (Err(_), Err(_)) => true,
+                _ => false,
+            });
+        (!agree).then(|| Disagreement {
+            part,
+            input: input.to_string(),
+            outcomes,
+        })
+    })
+}
+
+/// Runs `solve`, turning an error or a panic into a message.
+fn attempt(solve: impl FnOnce() -> Result<String, Error>) -> Outcome {
+    match panic::catch_unwind(AssertUnwindSafe(solve)) {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(err)) => Err(format!("error: {err:#}")),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Err(format!("panicked: {message}"))
+        }
+    }
+}
+
+/// Tries `options.cases` generated inputs and returns the first one the
+/// solvers disagree on, minimized, or `None` if they always agree.
+pub fn search(
+    solution: &dyn DynSolution,
+    options: DiffOptions,
+) -> Result<Option<Disagreement>, Error> {
+    let day = solution.day();
+    if solution.variants().is_empty() {
+        bail!("day {day:02} has no variants to compare");
+    }
+
+    for case in 0..options.cases as u64 {
+        let input = solution
+            .generate(options.size, &mut Rng::new(options.seed + case))
+            .ok_or_else(|| anyhow!("day {day:02} has no input generator"))?;
+        if let Err(err) = solution.parse(&input) {
+            bail!("the generator of day {day:02} wrote an input it cannot parse: {err:#}");
+        }
+
+        if let Some(found) = compare(solution, &input) {
+            return Ok(Some(minimize(solution, found)));
+        }
+    }
+    Ok(None)
+}
+
+/// Shrinks the input of `found` while the same part keeps disagreeing:
+/// first whole lines go, then the space separated words of each line.
+pub fn minimize(solution: &dyn DynSolution, found: Disagreement) -> Disagreement {
+    let part = found.part;
+    let check = |lines: &[String]| {
+        let input = lines
+            .iter()
+            .map(|line| format!("{line}\n"))
+            .collect::<String>();
+        compare(solution, &input).filter(|again| again.part == part)
+    };
+
+    let lines: Vec<String> = found.input.lines().map(str::to_string).collect();
+    let mut lines = shrink(lines, |lines| check(lines).is_some());
+    for i in 0..lines.len() {
+        let words: Vec<String> = lines[i].split(' ').map(str::to_string).collect();
+        let words = shrink(words, |words| {
+            let mut candidate = lines.clone();
+            candidate[i] = words.join(" ");
+            check(&candidate).is_some()
+        });
+        lines[i] = words.join(" ");
+    }
+
+    check(&lines).unwrap_or(found)
+}
+
+/// Removes chunks of `items`, halving their size down to single items, as
+/// long as what is left still `fails`.
+fn shrink<T: Clone>(mut items: Vec<T>, fails: impl Fn(&[T]) -> bool) -> Vec<T> {
+    let mut chunk = items.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < items.len() {
+            let end = (start + chunk).min(items.len());
+            let candidate = [&items[..start], &items[end..]].concat();
+            if !candidate.is_empty() && fails(&candidate) {
+                items = candidate;
+            } else {
+                start += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+    items
+}
+
+#[test]
+fn finds_and_minimizes_disagreements() {
+    use crate::solution::{Solution, Variant};
+
+    /// Sums numbers, with a variant that forgets those above 50.
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 99;
+        type Input = Vec<Vec<u32>>;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            let line = |line: &str| line.split(' ').map(str::parse).collect();
+            Ok(input.lines().map(line).collect::<Result<_, _>>()?)
+        }
+
+        fn part_1(input: &Self::Input) -> Result<String, Error> {
+            Ok(input.iter().flatten().sum::<u32>().to_string())
+        }
+
+        fn part_2(_: &Self::Input) -> Result<String, Error> {
+            Ok(String::new())
+        }
+
+        fn variants() -> Vec<Variant<Self::Input>> {
+            vec![Variant {
+                name: "small",
+                part: Part::One,
+                solve: |input| {
+                    let small = input.iter().flatten().filter(|&&n| n <= 50);
+                    Ok(small.sum::<u32>().to_string())
+                },
+            }]
+        }
+
+        fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+            let line = |rng: &mut Rng| {
+                let numbers: Vec<String> = (0..4).map(|_| rng.range(0..=60).to_string()).collect();
+                numbers.join(" ") + "\n"
+            };
+            Some((0..size).map(|_| line(rng)).collect())
+        }
+    }
+
+    let found = search(&Sum, DiffOptions::default()).unwrap().unwrap();
+    assert_eq!(found.part, Part::One);
+    assert_eq!(found.input.lines().count(), 1);
+    let number: u32 = found.input.trim().parse().unwrap();
+    assert!(number > 50);
+    assert_eq!(
+        found.outcomes,
+        [
+            ("main", Ok(number.to_string())),
+            ("small", Ok("0".to_string()))
+        ]
+    );
+
+    assert_eq!(compare(&Sum, "1 2\n3\n"), None);
+    assert_eq!(compare(&Sum, "not numbers\n"), None);
+    let outcome = attempt(|| -> Result<String, Error> { panic!("boom") });
+    assert_eq!(outcome, Err("panicked: boom".to_string()));
+}
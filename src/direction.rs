@@ -0,0 +1,171 @@
+//! Directions on maps where `y` grows downwards, with the offsets that step
+//! one cell towards them.
+
+use anyhow::{bail, Error, Result};
+
+use crate::point::Point;
+
+/// One of the four directions sharing an edge with a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub const fn offset(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::Right => Point::new(1, 0),
+            Self::Down => Point::new(0, 1),
+            Self::Left => Point::new(-1, 0),
+        }
+    }
+
+    /// A quarter turn clockwise.
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// A quarter turn counterclockwise.
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub const fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub const fn is_vertical(self) -> bool {
+        matches!(self, Self::Up | Self::Down)
+    }
+
+    /// The arrow pointing this way, as puzzle maps draw it.
+    pub const fn arrow(self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+}
+
+/// Reads an arrow (`^`, `>`, `v`, `<`) or a compass point (`N`, `E`, `S`, `W`).
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'N' => Ok(Self::Up),
+            '>' | 'E' => Ok(Self::Right),
+            'v' | 'S' => Ok(Self::Down),
+            '<' | 'W' => Ok(Self::Left),
+            _ => bail!("expected a direction out of `^>v<` or `NESW`, got `{c}`"),
+        }
+    }
+}
+
+/// One of the eight directions touching a cell, diagonals included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// The four diagonals, clockwise from up-right.
+    pub const DIAGONAL: [Self; 4] = [Self::UpRight, Self::DownRight, Self::DownLeft, Self::UpLeft];
+
+    pub const fn offset(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::UpRight => Point::new(1, -1),
+            Self::Right => Point::new(1, 0),
+            Self::DownRight => Point::new(1, 1),
+            Self::Down => Point::new(0, 1),
+            Self::DownLeft => Point::new(-1, 1),
+            Self::Left => Point::new(-1, 0),
+            Self::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    /// An eighth of a turn clockwise.
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// An eighth of a turn counterclockwise.
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub const fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+#[test]
+fn turns_and_reverses() {
+    for direction in Direction::ALL {
+        let offset = direction.offset();
+        // A quarter turn clockwise maps (x, y) to (-y, x)
+        assert_eq!(
+            direction.turn_right().offset(),
+            Point::new(-offset.y, offset.x)
+        );
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.opposite().offset(), -offset);
+        assert_eq!(Direction8::from(direction).offset(), offset);
+    }
+
+    for direction in Direction8::ALL {
+        assert_eq!(direction.opposite().offset(), -direction.offset());
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.turn_right().turn_right().offset(), {
+            let offset = direction.offset();
+            Point::new(-offset.y, offset.x)
+        });
+    }
+}
+
+#[test]
+fn parses_arrows_and_compass_points() {
+    let arrows: Vec<Direction> = "^>v<".chars().map(|c| c.try_into().unwrap()).collect();
+    let compass: Vec<Direction> = "NESW".chars().map(|c| c.try_into().unwrap()).collect();
+    assert_eq!(arrows, Direction::ALL);
+    assert_eq!(compass, Direction::ALL);
+    assert!(Direction::try_from('x').is_err());
+
+    let drawn: String = Direction::ALL.map(Direction::arrow).iter().collect();
+    assert_eq!(drawn, "^>v<");
+}
@@ -0,0 +1,241 @@
+//! Examples from the puzzle descriptions, kept in `examples/day_XX.toml` and
+//! checked by the sample tests of each day.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use anyhow::{bail, Context, Error, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::solution::Part;
+
+/// Examples of one day, in the order the puzzle description gives them:
+///
+/// ```toml
+/// [[example]]
+/// part = 1
+/// input = """
+/// 3   4
+/// 4   3
+/// """
+/// answer = "11"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Examples {
+    #[serde(default, rename = "example")]
+    examples: Vec<Example>,
+}
+
+/// An example input and the answer the puzzle description gives for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+/// Path of the examples of `day` inside `examples/`.
+pub fn path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(format!("day_{day:02}.toml"))
+}
+
+/// The examples of a part of `day`, for its sample tests.
+///
+/// # Panics
+///
+/// If `examples/day_XX.toml` cannot be read or has no example for the part.
+pub fn of(day: u8, part: Part) -> Vec<Example> {
+    let path = path(day);
+    let examples = Examples::load(&path).unwrap_or_else(|err| panic!("{err:#}"));
+    let examples: Vec<Example> = examples.part(part).cloned().collect();
+    assert!(
+        !examples.is_empty(),
+        "`{}` has no example for part {}, extract them with \
+         `cargo run --bin aoc -- examples {day} <page.html>`",
+        path.display(),
+        part.number()
+    );
+    examples
+}
+
+impl Examples {
+    /// Reads the examples stored at `path`, a missing file holding none.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read `{}`", path.display()))
+            }
+        };
+
+        toml::from_str(&text)
+            .with_context(|| format!("`{}` is not a valid examples file", path.display()))
+    }
+
+    pub fn save(&self, path: &Path, day: u8) -> Result<(), Error> {
+        let header = format!(
+            "# Examples from the description of day {day:02}, checked by its sample tests.\n\
+             # Extracted with `cargo run --bin aoc -- examples {day} <page.html>`, edit freely.\n\n"
+        );
+        let text = format!("{header}{}", toml::to_string(self)?);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create `{}`", parent.display()))?;
+        }
+        fs::write(path, text).with_context(|| format!("failed to write `{}`", path.display()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Example> + '_ {
+        self.examples.iter()
+    }
+
+    pub fn part(&self, part: Part) -> impl Iterator<Item = &Example> + '_ {
+        self.examples
+            .iter()
+            .filter(move |example| example.part == part.number())
+    }
+
+    /// Pulls the examples out of a puzzle page saved from the site.
+    ///
+    /// Each `<article>` describes a part. Every `<pre><code>` block in it is
+    /// an example, answered by the last emphasized `<code><em>` before the
+    /// next block; blocks followed by none are diagrams and skipped. When the
+    /// second part gives an answer without a block of its own, it applies to
+    /// the last example of the first part.
+    pub fn extract(page: &str) -> Result<Self, Error> {
+        static BLOCK: OnceLock<Regex> = OnceLock::new();
+        static ANSWER: OnceLock<Regex> = OnceLock::new();
+        let block = BLOCK.get_or_init(|| Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap());
+        let answer = ANSWER.get_or_init(|| {
+            Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap()
+        });
+        let last_answer = |text: &str| {
+            answer.captures_iter(text).last().map(|captures| {
+                let inner = captures.get(1).or_else(|| captures.get(2)).unwrap();
+                text_of(inner.as_str()).trim().to_string()
+            })
+        };
+
+        let mut articles: Vec<&str> = page
+            .split("<article")
+            .skip(1)
+            .map(|article| article.split_once("</article>").map_or(article, |(a, _)| a))
+            .collect();
+        if articles.is_empty() {
+            articles.push(page);
+        }
+
+        let mut examples = Vec::new();
+        for (article, part) in articles.into_iter().zip([1, 2]) {
+            let blocks: Vec<_> = block.captures_iter(article).collect();
+
+            let before_first = blocks
+                .first()
+                .map_or(article, |first| &article[..first.get(0).unwrap().start()]);
+            if part == 2 {
+                let reused = examples
+                    .iter()
+                    .rev()
+                    .find(|example: &&Example| example.part == 1);
+                if let (Some(reused), Some(answer)) = (reused, last_answer(before_first)) {
+                    examples.push(Example {
+                        part,
+                        input: reused.input.clone(),
+                        answer,
+                    });
+                }
+            }
+
+            for (i, captures) in blocks.iter().enumerate() {
+                let end = captures.get(0).unwrap().end();
+                let next = blocks
+                    .get(i + 1)
+                    .map_or(article.len(), |next| next.get(0).unwrap().start());
+                if let Some(answer) = last_answer(&article[end..next]) {
+                    examples.push(Example {
+                        part,
+                        input: text_of(&captures[1]),
+                        answer,
+                    });
+                }
+            }
+        }
+
+        if examples.is_empty() {
+            bail!("found no example followed by an emphasized answer in the page");
+        }
+        Ok(Self { examples })
+    }
+}
+
+/// The text of an HTML fragment, without its tags and with the entities the
+/// puzzle pages use decoded.
+fn text_of(html: &str) -> String {
+    static TAG: OnceLock<Regex> = OnceLock::new();
+    let tag = TAG.get_or_init(|| Regex::new(r"<[^>]*>").unwrap());
+
+    tag.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[test]
+fn extracts_examples_and_answers() {
+    let page = r#"
+<main>
+<article class="day-desc"><h2>--- Day 1: Sample ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>The pairs are <code>3</code> and <code>4</code>, so the total is <code><em>2</em></code>.</p>
+<p>A drawing that answers nothing:</p>
+<pre><code>&lt;<em>*</em>&gt;
+</code></pre>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Now the same list gives <code><em>12</em></code>.</p>
+<p>Another list:</p>
+<pre><code>1 &amp; 2
+</code></pre>
+<p>which gives <em><code>3</code></em>.</p>
+</article>
+</main>"#;
+
+    let examples = Examples::extract(page).unwrap();
+    let summary: Vec<(u8, &str, &str)> = examples
+        .iter()
+        .map(|example| {
+            (
+                example.part,
+                example.input.as_str(),
+                example.answer.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (1, "3   4\n4   3\n", "2"),
+            (2, "3   4\n4   3\n", "12"),
+            (2, "1 & 2\n", "3"),
+        ]
+    );
+    assert_eq!(examples.part(Part::Two).count(), 2);
+
+    let text = toml::to_string(&examples).unwrap();
+    assert_eq!(toml::from_str::<Examples>(&text).unwrap(), examples);
+
+    assert!(Examples::extract("<article><p>No examples</p></article>").is_err());
+}
@@ -0,0 +1,95 @@
+//! Random puzzle inputs of any size, to see how the solvers scale beyond the
+//! one personal input of each day.
+//!
+//! Days with a generator override [`Solution::generate`]; what `size` counts
+//! is up to each of them (lines, cells per side, digits...). The same day,
+//! size and seed always give the same input.
+//!
+//! [`Solution::generate`]: crate::solution::Solution::generate
+
+use std::ops::RangeInclusive;
+
+use anyhow::{anyhow, Error, Result};
+
+use crate::days;
+
+/// A small seeded random number generator (SplitMix64). It is written here
+/// rather than taken from a crate so a seed keeps giving the same input.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number below `n`, which must not be zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        // The bias is at most `n / 2^64`, invisible at these sizes
+        ((u128::from(self.next_u64()) * u128::from(n)) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        // Only the whole of `i64` has more values than a `u64` can count
+        match range.end().abs_diff(*range.start()).checked_add(1) {
+            Some(span) => range.start().wrapping_add_unsigned(self.below(span)),
+            None => self.next_u64() as i64,
+        }
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+}
+
+/// A random input of `day` of about `size`, the same for the same `seed`.
+pub fn generate(day: u8, size: usize, seed: u64) -> Result<String, Error> {
+    let solution = days::get(day).ok_or_else(|| anyhow!("day {day} is not between 1 and 25"))?;
+    solution
+        .generate(size, &mut Rng::new(seed))
+        .ok_or_else(|| anyhow!("day {day:02} has no input generator"))
+}
+
+#[test]
+fn generated_inputs_are_solvable() {
+    use crate::solution::Part;
+
+    let mut generators = 0;
+    for solution in days::ALL {
+        let day = solution.day();
+        let Ok(input) = generate(day, 20, 7) else {
+            continue;
+        };
+        generators += 1;
+
+        assert_eq!(generate(day, 20, 7).unwrap(), input, "day {day}");
+        assert_ne!(generate(day, 20, 8).unwrap(), input, "day {day}");
+
+        let parsed = solution
+            .parse(&input)
+            .unwrap_or_else(|err| panic!("day {day}: {err:#}\n{input}"));
+        for part in [Part::One, Part::Two] {
+            if let Err(err) = parsed.solve(part) {
+                panic!("day {day} part {}: {err:#}\n{input}", part.number());
+            }
+        }
+    }
+    assert!(generators >= 6);
+
+    let mut rng = Rng::new(1);
+    assert!((0..1000)
+        .map(|_| rng.range(-3..=3))
+        .all(|n| (-3..=3).contains(&n)));
+    assert!((0..1000).any(|_| rng.range(-3..=3) == 3));
+    assert!((0..1000).any(|_| rng.range(i64::MIN..=i64::MAX) < 0));
+    assert_eq!(rng.range(i64::MAX..=i64::MAX), i64::MAX);
+}
@@ -0,0 +1,337 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use anyhow::{Error, Result};
+
+use crate::direction::{Direction, Direction8};
+use crate::parse;
+use crate::point::Point;
+use crate::topology::{Bounded, Topology, Toroidal};
+
+/// A rectangular map stored row by row in a single `Vec`, indexed with a
+/// [`Point`] whose `y` is the row and `x` the column.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Wraps `cells`, given row by row.
+    ///
+    /// # Panics
+    ///
+    /// If there are not exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {width}x{height} grid needs {} cells",
+            width * height
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid by calling `cell` for every position, in reading order.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+            .map(&mut cell)
+            .collect();
+        Self::new(width, height, cells)
+    }
+
+    /// Parses a map of characters, converting each one with `cell`. The
+    /// first character it rejects, or a line not as long as the first one,
+    /// is reported as a [`ParseError`].
+    ///
+    /// [`ParseError`]: crate::parse::ParseError
+    pub fn parse_with(
+        input: &str,
+        cell: impl FnMut(char) -> Result<T, Error>,
+    ) -> Result<Self, Error> {
+        Ok(parse::grid(input, input, cell)?)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `point` falls inside the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        self.offset(point).is_some()
+    }
+
+    /// The space the grid covers, with nothing beyond its edges.
+    pub fn bounded(&self) -> Bounded {
+        Bounded::new(self.width as i32, self.height as i32)
+    }
+
+    /// The space the grid covers, its opposite edges joined.
+    pub fn toroidal(&self) -> Toroidal {
+        Toroidal::new(self.width as i32, self.height as i32)
+    }
+
+    /// The cell where `topology` places `point`: with [`Self::toroidal`],
+    /// the grid repeats in every direction.
+    pub fn get_in(&self, topology: &impl Topology, point: Point) -> Option<&T> {
+        self.get(topology.place(point)?)
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        point
+            .to_index(self.width)
+            .filter(|&offset| offset < self.cells.len())
+    }
+
+    fn point(&self, offset: usize) -> Point {
+        Point::from_index(offset, self.width)
+    }
+
+    /// The cell at `point`, or `None` when it falls outside the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Every position, in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|offset| self.point(offset))
+    }
+
+    /// Every cell with its position, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(offset, cell)| (self.point(offset), cell))
+    }
+
+    /// The up to four cells sharing an edge with `point`, clockwise from up.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.around(point, Direction::ALL.map(Direction::offset))
+    }
+
+    /// The up to eight cells around `point`, clockwise from up.
+    pub fn all_neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.around(point, Direction8::ALL.map(Direction8::offset))
+    }
+
+    fn around<const N: usize>(
+        &self,
+        point: Point,
+        offsets: [Point; N],
+    ) -> impl Iterator<Item = (Point, &T)> + '_ {
+        offsets.into_iter().filter_map(move |offset| {
+            let next = point + offset;
+            Some((next, self.get(next)?))
+        })
+    }
+
+    /// The first cell equal to `value`, in reading order.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// The first cell, in reading order, that satisfies `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|offset| self.point(offset))
+    }
+
+    /// The cells of each row, none if the grid has no cells.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // A width of 0 leaves no cells to split, but `chunks` panics on it
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of each column, top to bottom, none if the grid has no
+    /// cells.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        let width = if self.cells.is_empty() { 0 } else { self.width };
+        (0..width).map(|x| self.cells[x..].iter().step_by(self.width))
+    }
+
+    /// Converts every cell with `f`, keeping the layout.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// Turns the grid a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_row = self.height as i32 - 1;
+        Self::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, last_row - p.x)].clone()
+        })
+    }
+
+    /// Turns the grid a quarter turn counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_column = self.width as i32 - 1;
+        Self::from_fn(self.height, self.width, |p| {
+            self[Point::new(last_column - p.y, p.x)].clone()
+        })
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "{point:?} is outside the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the {width}x{height} grid"))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, Ok)
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+/// Grids from 1 to 11 cells a side, each cell drawn from `cell`, for the
+/// parser properties of the days reading a map.
+#[cfg(test)]
+pub(crate) fn grids<T: fmt::Debug>(
+    cell: impl proptest::strategy::Strategy<Value = T> + Clone,
+) -> impl proptest::strategy::Strategy<Value = Grid<T>> {
+    use proptest::strategy::Strategy;
+
+    (1..12usize, 1..12usize).prop_flat_map(move |(width, height)| {
+        proptest::collection::vec(cell.clone(), width * height)
+            .prop_map(move |cells| Grid::new(width, height, cells))
+    })
+}
+
+#[test]
+fn empty_grids_have_no_rows_nor_columns() {
+    for grid in [Grid::<u8>::new(0, 3, vec![]), Grid::from_fn(3, 0, |_| 0)] {
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.transpose().rows().count(), 0);
+    }
+}
+
+#[test]
+fn indexes_rows_first() {
+    let grid: Grid<char> = "abc\ndef\n".parse().unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+
+    assert_eq!(grid.get(Point::new(2, 0)), Some(&'c'));
+    assert_eq!(grid[Point::new(0, 1)], 'd');
+    assert_eq!(grid.get(Point::new(-1, 0)), None);
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+    assert_eq!(grid.get(Point::new(0, 2)), None);
+
+    assert_eq!(grid.find(&'e'), Some(Point::new(1, 1)));
+    assert_eq!(grid.find(&'z'), None);
+    assert_eq!(grid.to_string(), "abc\ndef\n");
+
+    let neighbours: String = grid.neighbours(Point::new(1, 0)).map(|(_, &c)| c).collect();
+    assert_eq!(neighbours, "cea");
+    let neighbours: String = grid
+        .all_neighbours(Point::new(0, 1))
+        .map(|(_, &c)| c)
+        .collect();
+    assert_eq!(neighbours, "abe");
+
+    let rows: Vec<&[char]> = grid.rows().collect();
+    assert_eq!(rows, [['a', 'b', 'c'], ['d', 'e', 'f']]);
+    let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+    assert_eq!(columns, ["ad", "be", "cf"]);
+}
+
+#[test]
+fn transposes_and_rotates() {
+    let grid: Grid<char> = "abc\ndef\n".parse().unwrap();
+
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+    assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    assert_eq!(grid.transpose().transpose(), grid);
+}
+
+#[test]
+fn rejects_ragged_and_invalid_maps() {
+    let digits = |c: char| c.to_digit(10).ok_or_else(|| anyhow::anyhow!("not a digit"));
+    let grid = Grid::parse_with("12\n34", digits).unwrap();
+    assert_eq!(grid.map(|&d| d * 2), Grid::new(2, 2, vec![2, 4, 6, 8]));
+
+    let err = Grid::parse_with("12\n3x", digits).unwrap_err();
+    assert_eq!(format!("{err:#}"), "line 2, column 2: not a digit");
+
+    let err = "ab\nc\n".parse::<Grid<char>>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 1: expected 2 columns like the first line, found 1"
+    );
+    assert!("".parse::<Grid<char>>().is_err());
+}
+
+#[test]
+fn wraps_around_in_a_toroidal_space() {
+    let grid: Grid<char> = "ab\ncd\n".parse().unwrap();
+    let far = Point::new(-3, 4);
+
+    assert_eq!(grid.get_in(&grid.toroidal(), far), Some(&'b'));
+    assert_eq!(grid.get_in(&grid.bounded(), far), None);
+    assert_eq!(grid.get_in(&grid.bounded(), Point::new(1, 1)), Some(&'d'));
+}
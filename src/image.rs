@@ -0,0 +1,236 @@
+//! Turns visualization frames into pictures: PPM and PNG stills, and
+//! animated GIFs.
+
+use std::collections::HashMap;
+use std::io::Write;
+
+use anyhow::{bail, Context, Error, Result};
+
+use crate::point::Point;
+use crate::visualize::{Frame, Mark};
+
+pub type Rgb = [u8; 3];
+
+/// The color of each kind of cell, and of marked cells whatever they hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    cells: HashMap<char, Rgb>,
+    active: Rgb,
+    found: Rgb,
+}
+
+impl Default for Palette {
+    /// Dark floor, grey walls, amber boxes, yellow for the active cells and
+    /// red for the found ones. Other cells get a color derived from their
+    /// character, so antennas of different frequencies stay apart.
+    fn default() -> Self {
+        Self {
+            cells: HashMap::from([
+                ('.', [15, 15, 35]),
+                ('#', [120, 120, 140]),
+                ('O', [181, 137, 0]),
+                ('[', [181, 137, 0]),
+                (']', [181, 137, 0]),
+            ]),
+            active: [255, 215, 0],
+            found: [220, 50, 47],
+        }
+    }
+}
+
+impl Palette {
+    pub fn with(mut self, cell: char, color: Rgb) -> Self {
+        self.cells.insert(cell, color);
+        self
+    }
+
+    pub fn color(&self, cell: char, mark: Option<Mark>) -> Rgb {
+        match mark {
+            Some(Mark::Active) => self.active,
+            Some(Mark::Found) => self.found,
+            None => self.cells.get(&cell).copied().unwrap_or_else(|| {
+                let hash = (cell as u32).wrapping_mul(2_654_435_761);
+                [16, 8, 0].map(|shift| (hash >> shift) as u8 | 0x40)
+            }),
+        }
+    }
+}
+
+/// An RGB picture, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Draws each cell of `frame` as a `scale` by `scale` square.
+    pub fn from_frame(frame: &Frame, palette: &Palette, scale: usize) -> Self {
+        let (width, height) = (frame.grid.width() * scale, frame.grid.height() * scale);
+        let marks: HashMap<Point, Mark> = frame.marks.iter().copied().collect();
+
+        let mut pixels = Vec::with_capacity(width * height);
+        for (y, cells) in frame.grid.rows().enumerate() {
+            let row: Vec<Rgb> = cells
+                .iter()
+                .enumerate()
+                .flat_map(|(x, &cell)| {
+                    let mark = marks.get(&Point::new(x as i32, y as i32)).copied();
+                    std::iter::repeat_n(palette.color(cell, mark), scale)
+                })
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&row);
+            }
+        }
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+
+    /// Writes the binary PPM (`P6`) encoding of the image.
+    pub fn write_ppm(&self, mut out: impl Write) -> Result<(), Error> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.bytes())?;
+        Ok(())
+    }
+
+    pub fn write_png(&self, out: impl Write) -> Result<(), Error> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().context("failed to start the PNG")?;
+        writer
+            .write_image_data(&self.bytes())
+            .context("failed to write the PNG")?;
+        Ok(())
+    }
+
+    /// The image as indexes into a palette of at most 256 colors, as GIF
+    /// frames need, or `None` when it has more colors than that.
+    fn indexed(&self) -> Option<(Vec<u8>, Vec<u8>)> {
+        let mut colors: HashMap<Rgb, u8> = HashMap::new();
+        let mut palette = Vec::new();
+        let mut indexes = Vec::with_capacity(self.pixels.len());
+
+        for &pixel in &self.pixels {
+            let index = match colors.get(&pixel) {
+                Some(&index) => index,
+                None => {
+                    let index = u8::try_from(colors.len()).ok()?;
+                    colors.insert(pixel, index);
+                    palette.extend(pixel);
+                    index
+                }
+            };
+            indexes.push(index);
+        }
+
+        Some((indexes, palette))
+    }
+}
+
+/// Writes images one after the other as the frames of a looping GIF.
+pub struct GifWriter<W: Write> {
+    encoder: gif::Encoder<W>,
+    width: usize,
+    height: usize,
+    /// Time each frame stays on screen, in hundredths of a second.
+    delay: u16,
+}
+
+impl<W: Write> GifWriter<W> {
+    pub fn new(out: W, width: usize, height: usize, delay: u16) -> Result<Self, Error> {
+        let (w, h) = (
+            u16::try_from(width).context("the GIF is too wide")?,
+            u16::try_from(height).context("the GIF is too tall")?,
+        );
+        let mut encoder = gif::Encoder::new(out, w, h, &[]).context("failed to start the GIF")?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        Ok(Self {
+            encoder,
+            width,
+            height,
+            delay,
+        })
+    }
+
+    pub fn write(&mut self, image: &Image) -> Result<(), Error> {
+        if (image.width, image.height) != (self.width, self.height) {
+            bail!(
+                "a {}x{} frame does not fit a {}x{} GIF",
+                image.width,
+                image.height,
+                self.width,
+                self.height
+            );
+        }
+
+        let (w, h) = (self.width as u16, self.height as u16);
+        let mut frame = match image.indexed() {
+            Some((indexes, palette)) => {
+                gif::Frame::from_palette_pixels(w, h, indexes, palette, None)
+            }
+            None => gif::Frame::from_rgb(w, h, &image.bytes()),
+        };
+        frame.delay = self.delay;
+        self.encoder
+            .write_frame(&frame)
+            .context("failed to write a GIF frame")
+    }
+}
+
+#[test]
+fn draws_frames_as_pictures() {
+    use crate::grid::Grid;
+
+    let grid: Grid<char> = "#.\n.#\n".parse().unwrap();
+    let frame = Frame::new("", grid).mark([Point::new(1, 0)], Mark::Active);
+    let palette = Palette::default().with('#', [1, 2, 3]);
+
+    let image = Image::from_frame(&frame, &palette, 2);
+    assert_eq!((image.width(), image.height()), (4, 4));
+    let top_row: Vec<Rgb> = image.pixels[..4].to_vec();
+    assert_eq!(
+        top_row,
+        [[1, 2, 3], [1, 2, 3], [255, 215, 0], [255, 215, 0]]
+    );
+    assert_eq!(image.pixels[8], [15, 15, 35]);
+
+    let mut ppm = Vec::new();
+    image.write_ppm(&mut ppm).unwrap();
+    assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+    assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+
+    let mut png = Vec::new();
+    image.write_png(&mut png).unwrap();
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+
+    let mut gif = Vec::new();
+    let mut writer = GifWriter::new(&mut gif, 4, 4, 5).unwrap();
+    writer.write(&image).unwrap();
+    writer.write(&image).unwrap();
+    assert!(writer
+        .write(&Image::from_frame(&frame, &palette, 1))
+        .is_err());
+    drop(writer);
+    assert!(gif.starts_with(b"GIF89a"));
+    assert_eq!(gif.last(), Some(&0x3b));
+}
@@ -0,0 +1,101 @@
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Error, Result};
+
+use crate::generate;
+
+/// Text the template ships in `puzzle_input/day_XX.txt` until a personal
+/// input replaces it.
+pub(crate) const PLACEHOLDER: &str = "<your puzzle input>";
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// `puzzle_input/day_XX.txt` at the root of the repository.
+    #[default]
+    Default,
+    /// Any file, given with `--input <file>`.
+    File(PathBuf),
+    /// Standard input, given with `--input -`.
+    Stdin,
+    /// A random input from the generator of the day, see [`generate`].
+    Generated { size: usize, seed: u64 },
+}
+
+impl FromStr for InputSource {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::File(PathBuf::from(path))),
+        }
+    }
+}
+
+/// Path of the personal input of `day` inside `puzzle_input/`.
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("puzzle_input")
+        .join(format!("day_{day:02}.txt"))
+}
+
+/// Reads the puzzle input of `day` from `source`, refusing a missing file or
+/// one that still holds the template placeholder.
+pub fn load(day: u8, source: &InputSource) -> Result<String, Error> {
+    let (input, origin) = match source {
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .context("failed to read the puzzle input from stdin")?;
+            (input, "stdin".to_string())
+        }
+        InputSource::Default => read(&default_path(day))?,
+        InputSource::File(path) => read(path)?,
+        InputSource::Generated { size, seed } => return generate::generate(day, *size, *seed),
+    };
+
+    if input.trim() == PLACEHOLDER {
+        bail!("{origin} still holds the `{PLACEHOLDER}` placeholder, paste your puzzle input for day {day:02} there");
+    }
+    if input.trim().is_empty() {
+        bail!("{origin} is empty, paste your puzzle input for day {day:02} there");
+    }
+
+    Ok(input)
+}
+
+fn read(path: &Path) -> Result<(String, String), Error> {
+    let origin = format!("`{}`", path.display());
+    if !path.exists() {
+        bail!("{origin} does not exist, save your puzzle input there or pass `--input <file>`");
+    }
+
+    let input =
+        std::fs::read_to_string(path).with_context(|| format!("failed to read {origin}"))?;
+    Ok((input, origin))
+}
+
+#[test]
+fn rejects_placeholder_and_missing_files() {
+    let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let placeholder = dir.join("day_16.txt");
+    std::fs::write(&placeholder, "<your puzzle input>\n").unwrap();
+    let err = load(16, &InputSource::File(placeholder)).unwrap_err();
+    assert!(err.to_string().contains("placeholder"));
+
+    let missing = dir.join("missing.txt");
+    let err = load(16, &InputSource::File(missing)).unwrap_err();
+    assert!(err.to_string().contains("does not exist"));
+
+    let personal = dir.join("day_01.txt");
+    std::fs::write(&personal, "3   4\n").unwrap();
+    assert_eq!(load(1, &InputSource::File(personal)).unwrap(), "3   4\n");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,22 @@
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod differential;
+pub mod direction;
+pub mod examples;
+pub mod generate;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod memo;
+pub mod parse;
+pub mod point;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod stepper;
+pub mod topology;
+pub mod union_find;
+pub mod visualize;
@@ -0,0 +1,155 @@
+//! Memoization of pure recursive functions, so a day does not have to thread
+//! its own cache through every call.
+//!
+//! A [`Memo`] only needs `&self`, so recursive calls and threads can share
+//! one, and kept in the parsed input it carries what part 1 computed over to
+//! part 2. `bench` parses the input again before every timed run, so a warm
+//! cache never makes a run look faster.
+
+use std::fmt;
+use std::hash::Hash;
+use std::sync::{Mutex, MutexGuard};
+
+use rustc_hash::FxHashMap as HashMap;
+
+/// The values a function returned, by its arguments.
+#[derive(Debug)]
+pub struct Memo<K, V> {
+    state: Mutex<State<K, V>>,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            state: Mutex::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State<K, V> {
+    values: HashMap<K, V>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<K, V> Default for State<K, V> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::default(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+/// How well a [`Memo`] is doing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// Calls answered from the cache.
+    pub hits: u64,
+    /// Calls that had to compute their value.
+    pub misses: u64,
+    /// Values held.
+    pub entries: usize,
+}
+
+impl Stats {
+    /// The share of calls answered from the cache, from 0 to 1.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            calls => self.hits as f64 / calls as f64,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hits), {} entries",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.entries
+        )
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value for `key`, computed by `compute` the first time only.
+    ///
+    /// `compute` is given the memo back to make its recursive calls through
+    /// it. The cache is not locked while it runs, so two threads may both
+    /// compute a missing value, which is harmless for a pure function.
+    pub fn get(&self, key: K, compute: impl FnOnce(&Self, &K) -> V) -> V {
+        {
+            let mut state = self.lock();
+            if let Some(value) = state.values.get(&key).cloned() {
+                state.hits += 1;
+                return value;
+            }
+            state.misses += 1;
+        }
+
+        let value = compute(self, &key);
+        self.lock().values.insert(key, value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> Stats {
+        let state = self.lock();
+        Stats {
+            hits: state.hits,
+            misses: state.misses,
+            entries: state.values.len(),
+        }
+    }
+
+    /// Forgets every value and resets the statistics.
+    pub fn clear(&self) {
+        *self.lock() = State::default();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State<K, V>> {
+        // A panic in `compute` never leaves the state half updated
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[test]
+fn caches_recursive_calls() {
+    fn fibonacci(memo: &Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get(n, |memo, &n| {
+            fibonacci(memo, n - 1) + fibonacci(memo, n - 2)
+        })
+    }
+
+    let memo = Memo::new();
+    assert_eq!(fibonacci(&memo, 90), 2_880_067_194_370_816_120);
+    // Each value from 2 to 90 is computed once and looked up once, but for
+    // the two that only one other value needs
+    let stats = memo.stats();
+    assert_eq!((stats.misses, stats.entries), (89, 89));
+    assert_eq!(stats.hits, 87);
+
+    assert_eq!(fibonacci(&memo, 50), 12_586_269_025);
+    assert_eq!(memo.stats().hits, 88);
+    assert_eq!(
+        memo.stats().to_string(),
+        "88 hits, 89 misses (49.7% hits), 89 entries"
+    );
+
+    memo.clear();
+    assert_eq!(memo.stats().hit_rate(), 0.0);
+}
@@ -0,0 +1,435 @@
+//! Errors that point at the exact place where a puzzle input stopped making
+//! sense, so a malformed input is reported instead of panicking, and parsers
+//! for the shapes puzzle inputs keep coming in: numbers in text, sections
+//! between blank lines, delimited records and character maps.
+//!
+//! Every parser takes the whole `input` along with the slice of it to parse,
+//! so its errors point at the right line wherever that slice comes from.
+
+use std::any::type_name;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::Error;
+
+use crate::grid::Grid;
+
+/// Where and why parsing a puzzle input failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in when the error leaves a day's `parse`, see `DynSolution`.
+    pub day: Option<u8>,
+    /// Line of the offending text, counting from 1.
+    pub line: usize,
+    /// Column of the offending text in characters, counting from 1.
+    pub column: usize,
+    pub text: String,
+    /// The whole line holding the offending text, shown by [`Self::diagnostic`].
+    pub source_line: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Points at `text`, which must be a slice of `input` (as `lines()`,
+    /// `split()` and regex captures return), to work out its line and column.
+    /// Text borrowed from elsewhere is searched for instead.
+    pub fn new(input: &str, text: &str, message: impl Into<String>) -> Self {
+        let start = (text.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + text.len() <= input.len())
+            .or_else(|| input.find(text))
+            .unwrap_or(0);
+
+        let line_start = input[..start].rfind('\n').map_or(0, |newline| newline + 1);
+        let line_end = input[start..]
+            .find('\n')
+            .map_or(input.len(), |newline| start + newline);
+
+        Self {
+            day: None,
+            line: input[..start].matches('\n').count() + 1,
+            column: input[line_start..start].chars().count() + 1,
+            text: text.to_string(),
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// The error followed by the offending line, with the text underlined:
+    ///
+    /// ```text
+    /// error: day 01, line 3, column 7: expected a location ID, found `x12`
+    ///   3 | 12    x12
+    ///     |       ^^^
+    /// ```
+    pub fn diagnostic(&self) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent = " ".repeat(self.column - 1);
+        let underline = "^".repeat(self.text.chars().count().max(1));
+
+        format!(
+            "error: {self}\n  {number} | {}\n  {gutter} | {indent}{underline}",
+            self.source_line
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day:02}, ")?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `text`, a slice of `input`, describing what was expected if it
+/// fails.
+pub fn field<T: FromStr>(input: &str, text: &str, what: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(input, text, format!("expected {what}, found `{text}`")))
+}
+
+/// Every integer in `text`, a slice of `input`, whatever surrounds them:
+/// `p=0,4 v=3,-3` gives `[0, 4, 3, -3]`. A `-` or `+` right before the digits
+/// is their sign, unless it follows a digit: `1-3` gives `[1, 3]`.
+pub fn ints<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    let bytes = text.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let mut start = i;
+        if start > 0
+            && matches!(bytes[start - 1], b'-' | b'+')
+            && (start < 2 || !bytes[start - 2].is_ascii_digit())
+        {
+            start -= 1;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        let number = &text[start..i];
+        numbers.push(number.parse().map_err(|_| {
+            let message = format!(
+                "expected a number fitting in `{}`, found `{number}`",
+                type_name::<T>()
+            );
+            ParseError::new(input, number, message)
+        })?);
+    }
+    Ok(numbers)
+}
+
+/// Exactly `N` integers of `text`, as [`ints`] finds them, `what` saying
+/// where they come from when there are more or fewer.
+pub fn ints_exact<T: FromStr, const N: usize>(
+    input: &str,
+    text: &str,
+    what: &str,
+) -> Result<[T; N], ParseError> {
+    let numbers = ints(input, text)?;
+    let found = numbers.len();
+    numbers.try_into().map_err(|_| {
+        ParseError::new(
+            input,
+            text,
+            format!("expected {N} numbers in {what}, found {found}"),
+        )
+    })
+}
+
+/// The whitespace separated fields of `text`, a slice of `input`, each
+/// parsed as `what`.
+pub fn words<T: FromStr>(input: &str, text: &str, what: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace()
+        .map(|word| field(input, word, what))
+        .collect()
+}
+
+/// The fields of `text`, a slice of `input`, between each `delimiter`, each
+/// parsed as `what`. Spaces around a field are ignored.
+pub fn records<T: FromStr>(
+    input: &str,
+    text: &str,
+    delimiter: char,
+    what: &str,
+) -> Result<Vec<T>, ParseError> {
+    text.split(delimiter)
+        .map(|record| field(input, record.trim(), what))
+        .collect()
+}
+
+/// `text`, a slice of `input`, split around the first `delimiter`. `what`
+/// describes the whole of it when there is no `delimiter`.
+pub fn pair<'a>(
+    input: &str,
+    text: &'a str,
+    delimiter: &str,
+    what: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(input, text, format!("expected {what}")))
+}
+
+/// Every line of `text` parsed with `line`. Blank lines at the end are left
+/// out, any other goes to `line` like the rest, which should reject it if an
+/// empty line means nothing.
+pub fn lines<'a, T>(
+    text: &'a str,
+    line: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    text.trim_end().lines().map(line).collect()
+}
+
+/// The sections of `input` between blank lines. Blank lines at the end are
+/// left out, any other ends a section, so a blank line at the start or two
+/// in a row give an empty section.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    // Where the current section starts, and where its last line ends
+    let mut section: Option<(usize, usize)> = None;
+
+    for line in input.trim_end().lines() {
+        let offset = offset(input, line);
+        if line.trim().is_empty() {
+            let (start, end) = section.take().unwrap_or((offset, offset));
+            sections.push(&input[start..end]);
+        } else {
+            let start = section.map_or(offset, |(start, _)| start);
+            section = Some((start, offset + line.len()));
+        }
+    }
+    if let Some((start, end)) = section {
+        sections.push(&input[start..end]);
+    }
+    sections
+}
+
+/// The `N` [`sections`] of `input`, which `names` describes in errors: a
+/// missing section is reported at the end of the input, an extra one at the
+/// blank line before it.
+pub fn split_sections<'a, const N: usize>(
+    input: &'a str,
+    names: [&str; N],
+) -> Result<[&'a str; N], ParseError> {
+    let sections = sections(input);
+
+    if let Some(&last) = sections[..N.min(sections.len())].last() {
+        if sections.len() > N {
+            // An empty section is the blank line that ends it
+            let after = offset(input, last) + last.len();
+            let blank = match last {
+                "" => last,
+                _ => input[after..].lines().nth(1).unwrap_or_default(),
+            };
+            let message = match N {
+                1 => format!("expected nothing after {}", names[0]),
+                _ => format!(
+                    "expected a single blank line between {} and {}",
+                    names[N - 2],
+                    names[N - 1]
+                ),
+            };
+            return Err(ParseError::new(input, blank, message));
+        }
+    }
+
+    let found = sections.len();
+    sections.try_into().map_err(|_| {
+        let end = input.trim_end().len();
+        let message = match found {
+            0 => format!("expected {}", names[0]),
+            _ => format!("expected {} after a blank line", names[found]),
+        };
+        ParseError::new(input, &input[end..end], message)
+    })
+}
+
+/// Parses `text`, a slice of `input`, as a map of characters, converting
+/// each one with `cell`. The first character it rejects, or a line not as
+/// long as the first one, is reported as a [`ParseError`].
+pub fn grid<T>(
+    input: &str,
+    text: &str,
+    mut cell: impl FnMut(char) -> Result<T, Error>,
+) -> Result<Grid<T>, ParseError> {
+    let mut width = None;
+    let mut height = 0;
+    let mut cells = Vec::new();
+
+    for line in text.lines() {
+        let before = cells.len();
+        for (i, c) in line.char_indices() {
+            let value = cell(c).map_err(|err| {
+                ParseError::new(input, &line[i..i + c.len_utf8()], format!("{err:#}"))
+            })?;
+            cells.push(value);
+        }
+
+        let length = cells.len() - before;
+        match width {
+            None => width = Some(length),
+            Some(width) if width != length => {
+                let message =
+                    format!("expected {width} columns like the first line, found {length}");
+                return Err(ParseError::new(input, line, message));
+            }
+            Some(_) => {}
+        }
+        height += 1;
+    }
+
+    match width {
+        None | Some(0) => Err(ParseError::new(input, text, "the map is empty")),
+        Some(width) => Ok(Grid::new(width, height, cells)),
+    }
+}
+
+/// Where `text`, a slice of `input`, starts in it.
+fn offset(input: &str, text: &str) -> usize {
+    text.as_ptr() as usize - input.as_ptr() as usize
+}
+
+/// Checks that `parse` refuses `input` once a character of its line `line`,
+/// counting from 0 and picked by `column`, is replaced by `x`, with a
+/// [`ParseError`] pointing at that line rather than skipping or misreading
+/// it. An empty line has nothing to replace, so the case is rejected.
+#[cfg(test)]
+pub(crate) fn check_corrupted<T>(
+    input: &str,
+    line: usize,
+    column: proptest::sample::Index,
+    parse: impl FnOnce(&str) -> Result<T, Error>,
+) -> Result<(), proptest::test_runner::TestCaseError> {
+    let text = input.lines().nth(line).unwrap_or_default();
+    proptest::prop_assume!(!text.is_empty());
+
+    let corrupted = corrupt(input, line, column.index(text.chars().count()));
+    let Err(err) = parse(&corrupted) else {
+        let message = format!("accepted the corrupted input\n{corrupted}");
+        return Err(proptest::test_runner::TestCaseError::fail(message));
+    };
+    let err = err.downcast_ref::<ParseError>();
+    proptest::prop_assert_eq!(err.map(|err| err.line), Some(line + 1));
+    Ok(())
+}
+
+/// `input` with the character at `column` of line `line`, both counting from
+/// 0, replaced by `x`.
+#[cfg(test)]
+fn corrupt(input: &str, line: usize, column: usize) -> String {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| {
+            let text: String = if i == line {
+                let mut chars: Vec<char> = text.chars().collect();
+                chars[column] = 'x';
+                chars.into_iter().collect()
+            } else {
+                text.to_string()
+            };
+            text + "\n"
+        })
+        .collect()
+}
+
+#[test]
+fn points_at_the_offending_text() {
+    let input = "12   34\n56   x12\n";
+    let line = input.lines().nth(1).unwrap();
+    let text = line.split_whitespace().nth(1).unwrap();
+
+    let mut err = field::<i32>(input, text, "a location ID").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 6));
+    assert_eq!(err.source_line, "56   x12");
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 6: expected a location ID, found `x12`"
+    );
+
+    err.day = Some(1);
+    assert_eq!(
+        err.diagnostic(),
+        "\
+error: day 01, line 2, column 6: expected a location ID, found `x12`
+  2 | 56   x12
+    |      ^^^"
+    );
+
+    // Text that is not a slice of the input is looked up
+    let err = ParseError::new(input, "34", "odd");
+    assert_eq!((err.line, err.column), (1, 6));
+}
+
+#[test]
+fn parses_common_input_shapes() {
+    let input = "p=0,4 v=3,-3\nButton A: X+94, Y+34\n1-3 a: 12,x\n";
+    let rows: Vec<&str> = input.lines().collect();
+
+    assert_eq!(ints::<i32>(input, rows[0]).unwrap(), [0, 4, 3, -3]);
+    assert_eq!(ints::<u32>(input, rows[1]).unwrap(), [94, 34]);
+    assert_eq!(ints::<i32>(input, rows[2]).unwrap(), [1, 3, 12]);
+    let err = ints::<u8>(input, "v=300").unwrap_err();
+    assert_eq!(
+        err.message,
+        "expected a number fitting in `u8`, found `300`"
+    );
+    let [x, y] = ints_exact::<i64, 2>(input, rows[1], "a button").unwrap();
+    assert_eq!((x, y), (94, 34));
+    let err = ints_exact::<i64, 2>(input, rows[0], "a button").unwrap_err();
+    assert_eq!(err.message, "expected 2 numbers in a button, found 4");
+
+    let (range, rest) = pair(input, rows[2], ":", "a password").unwrap();
+    assert_eq!((range, rest), ("1-3 a", " 12,x"));
+    assert!(pair(input, rows[0], ":", "a password").is_err());
+    let err = records::<u32>(input, rest, ',', "a number").unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (3, 11, "x"));
+    assert_eq!(
+        words::<String>(input, rows[0], "a word").unwrap(),
+        ["p=0,4", "v=3,-3"]
+    );
+    let lengths = lines(input, |line| Ok(line.len())).unwrap();
+    assert_eq!(lengths, [12, 20, 11]);
+
+    let input = "#.\n.#\n\n<>^\nv\n\n";
+    assert_eq!(sections(input), ["#.\n.#", "<>^\nv"]);
+    assert_eq!(sections("\na\n\n\nb\n"), ["", "a", "", "b"]);
+    let [map, moves] = split_sections(input, ["the map", "the moves"]).unwrap();
+    assert_eq!(moves, "<>^\nv");
+    let err = split_sections(input, ["the map"]).unwrap_err();
+    assert_eq!((err.line, err.column), (3, 1));
+    assert_eq!(err.message, "expected nothing after the map");
+    let err = split_sections("a\n\n\nb\n", ["the map", "the moves"]).unwrap_err();
+    assert_eq!((err.line, err.column), (3, 1));
+    assert_eq!(
+        err.message,
+        "expected a single blank line between the map and the moves"
+    );
+    let err = split_sections(map, ["the map", "the moves"]).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(err.message, "expected the moves after a blank line");
+
+    assert_eq!(grid(input, map, Ok).unwrap().to_string(), "#.\n.#\n");
+    let err = grid(input, moves, |c| match c {
+        '<' | '>' | '^' => Ok(c),
+        _ => Err(anyhow::anyhow!("expected a move")),
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "line 5, column 1: expected a move");
+}
@@ -0,0 +1,167 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position, or an offset between two positions, on a puzzle map. `x` grows
+/// to the right and `y` downwards, so `y` is the row and `x` the column.
+///
+/// Points are ordered in reading order: by row, then by column.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// The point stored at `index` of a grid laid out row by row.
+    pub fn from_index(index: usize, width: usize) -> Self {
+        Self::new((index % width) as i32, (index / width) as i32)
+    }
+
+    /// Where the point is stored in a grid laid out row by row, or `None` if
+    /// it lies left, right or above such a grid.
+    pub fn to_index(self, width: usize) -> Option<usize> {
+        let x = usize::try_from(self.x).ok().filter(|&x| x < width)?;
+        let y = usize::try_from(self.y).ok()?;
+        Some(y * width + x)
+    }
+
+    /// Number of orthogonal steps between the two points.
+    pub fn manhattan(self, other: Self) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Number of steps between the two points when diagonal steps are allowed.
+    pub fn chebyshev(self, other: Self) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The smallest offset with whole coordinates pointing the same way, so
+    /// that repeating it visits every grid cell on the line.
+    pub fn reduced(self) -> Self {
+        match gcd(self.x.abs(), self.y.abs()) {
+            0 => self,
+            divisor => Self::new(self.x / divisor, self.y / divisor),
+        }
+    }
+}
+
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: i32) -> Self::Output {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Ord for Point {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[test]
+fn adds_and_subtracts_offsets() {
+    let antenna = Point::new(4, 3);
+    let other = Point::new(5, 5);
+    let offset = other - antenna;
+
+    assert_eq!(offset, Point::new(1, 2));
+    assert_eq!(other + offset, Point::new(6, 7));
+    assert_eq!(antenna - offset, Point::new(3, 1));
+    assert_eq!(antenna + offset * 3, Point::new(7, 9));
+    assert_eq!(-offset, Point::new(-1, -2));
+
+    let mut robot = Point::ZERO;
+    robot += offset;
+    robot -= Point::from((3, 3));
+    assert_eq!(robot, Point::new(-2, -1));
+}
+
+#[test]
+fn measures_and_reduces() {
+    let a = Point::new(1, -2);
+    let b = Point::new(4, 2);
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a.chebyshev(b), 4);
+
+    assert_eq!(Point::new(6, -4).reduced(), Point::new(3, -2));
+    assert_eq!(Point::new(0, -5).reduced(), Point::new(0, -1));
+    assert_eq!(Point::ZERO.reduced(), Point::ZERO);
+}
+
+#[test]
+fn converts_grid_indices_and_orders_by_row() {
+    assert_eq!(Point::from_index(7, 3), Point::new(1, 2));
+    assert_eq!(Point::new(1, 2).to_index(3), Some(7));
+    assert_eq!(Point::new(3, 0).to_index(3), None);
+    assert_eq!(Point::new(0, -1).to_index(3), None);
+
+    let mut points = vec![Point::new(0, 1), Point::new(5, 0), Point::new(1, 0)];
+    points.sort();
+    assert_eq!(
+        points,
+        [Point::new(1, 0), Point::new(5, 0), Point::new(0, 1)]
+    );
+}
@@ -0,0 +1,607 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail, Context, Error, Result};
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::answers::{self, Answers, Check};
+use crate::client::{self, AnswerLog, Client, Verdict};
+use crate::days;
+use crate::differential::{self, DiffOptions};
+use crate::examples::{self, Examples};
+use crate::input::{self, InputSource};
+use crate::parse::ParseError;
+use crate::solution::{DynSolution, Part};
+use crate::visualize::Settings;
+
+/// Which days a command applies to: a single day or the whole calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl DaySelection {
+    pub fn solutions(self) -> Result<Vec<&'static dyn DynSolution>, Error> {
+        match self {
+            DaySelection::All => Ok(days::ALL.to_vec()),
+            DaySelection::Day(day) => days::get(day)
+                .map(|solution| vec![solution])
+                .ok_or_else(|| anyhow!("there is no day {day}, pick one from 1 to 25")),
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(DaySelection::All);
+        }
+        match s.parse() {
+            Ok(day @ 1..=25) => Ok(DaySelection::Day(day)),
+            _ => bail!("expected a day from 1 to 25 or `all`, got `{s}`"),
+        }
+    }
+}
+
+/// Which parts of a day to solve, chosen with `--part 1`, `--part 2` or
+/// `--part both`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PartSelection {
+    One,
+    Two,
+    #[default]
+    Both,
+}
+
+impl PartSelection {
+    pub fn parts(self) -> &'static [Part] {
+        match self {
+            PartSelection::One => &[Part::One],
+            PartSelection::Two => &[Part::Two],
+            PartSelection::Both => &[Part::One, Part::Two],
+        }
+    }
+}
+
+impl FromStr for PartSelection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(PartSelection::One),
+            "2" => Ok(PartSelection::Two),
+            "both" => Ok(PartSelection::Both),
+            _ => bail!("expected `1`, `2` or `both`, got `{s}`"),
+        }
+    }
+}
+
+/// How `run` prints its results, chosen with `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// A block per day, or a summary table for `all`.
+    #[default]
+    Text,
+    /// One JSON object per part, in an array.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => bail!("expected `text` or `json`, got `{s}`"),
+        }
+    }
+}
+
+/// Options of `run` beyond which parts to solve.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RunOptions {
+    pub source: InputSource,
+    pub format: Format,
+    /// Solve the days on all cores, at the cost of noisier timings.
+    pub parallel: bool,
+    /// Where to show the steps of the solvers that have a visualization.
+    pub visualize: Option<Settings>,
+}
+
+/// How a part fared, compared with `answers.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Correct,
+    Wrong,
+    /// Solved, but nothing is recorded to compare with.
+    Unrecorded,
+    Error,
+    /// The day has no puzzle input yet.
+    Skipped,
+}
+
+/// The result of one part of one day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartOutcome {
+    pub day: u8,
+    #[serde(serialize_with = "part_number")]
+    pub part: Part,
+    pub answer: Option<String>,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(rename = "elapsed_ms", serialize_with = "milliseconds")]
+    pub elapsed: Duration,
+}
+
+fn part_number<S: serde::Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(part.number())
+}
+
+fn milliseconds<S: serde::Serializer>(
+    elapsed: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(elapsed.as_secs_f64() * 1000.0)
+}
+
+/// The parts solved for one day, and the full diagnostic when its input
+/// could not be read or parsed.
+struct DayOutcome {
+    day: u8,
+    diagnostic: Option<String>,
+    parts: Vec<PartOutcome>,
+}
+
+/// Runs the selected parts of the selected days, failing if any of them
+/// returned an error or, on the personal input, an answer other than the one
+/// recorded in `answers.toml`. With `all`, days without an input are skipped.
+pub fn run(days: DaySelection, parts: PartSelection, options: &RunOptions) -> Result<(), Error> {
+    let source = &options.source;
+    if days == DaySelection::All && *source != InputSource::Default {
+        bail!("`--input` applies to a single day, each day of `all` reads its own input");
+    }
+    if options.visualize.is_some() && (days == DaySelection::All || options.parallel) {
+        bail!("`--visualize` shows a single day at a time");
+    }
+
+    // Recorded answers belong to the personal inputs, not to any other file
+    let answers = match source {
+        InputSource::Default => Answers::load(&answers::default_path())?,
+        _ => Answers::default(),
+    };
+
+    let skip_missing = days == DaySelection::All;
+    let solve = |solution: &&dyn DynSolution| {
+        let visualize = options.visualize.as_ref();
+        solve_day(*solution, parts, source, &answers, skip_missing, visualize)
+    };
+    let solutions = days.solutions()?;
+    let outcomes: Vec<DayOutcome> = if options.parallel {
+        solutions.par_iter().map(solve).collect()
+    } else {
+        solutions.iter().map(solve).collect()
+    };
+
+    match (options.format, days) {
+        (Format::Json, _) => {
+            let rows: Vec<&PartOutcome> = outcomes.iter().flat_map(|day| &day.parts).collect();
+            println!("{}", serde_json::to_string_pretty(&rows)?);
+        }
+        (Format::Text, DaySelection::All) => print_table(&outcomes),
+        (Format::Text, DaySelection::Day(_)) => outcomes.iter().for_each(print_day),
+    }
+
+    let failures = outcomes
+        .iter()
+        .flat_map(|day| &day.parts)
+        .filter(|part| matches!(part.status, Status::Wrong | Status::Error))
+        .count();
+    if failures > 0 {
+        bail!("{failures} part(s) failed");
+    }
+
+    Ok(())
+}
+
+/// Entry point of the per-day binaries in `src/bin/`, which solve both parts.
+pub fn run_day(day: u8) -> Result<(), Error> {
+    run(
+        DaySelection::Day(day),
+        PartSelection::Both,
+        &RunOptions::default(),
+    )
+}
+
+/// Solves the selected parts of a day and checks them against `answers`.
+fn solve_day(
+    solution: &dyn DynSolution,
+    parts: PartSelection,
+    source: &InputSource,
+    answers: &Answers,
+    skip_missing: bool,
+    visualize: Option<&Settings>,
+) -> DayOutcome {
+    let day = solution.day();
+    let unsolved = |status, err: &Error| DayOutcome {
+        day,
+        diagnostic: Some(diagnostic(err)),
+        parts: parts
+            .parts()
+            .iter()
+            .map(|&part| PartOutcome {
+                day,
+                part,
+                answer: None,
+                status,
+                expected: None,
+                error: Some(format!("{err:#}")),
+                elapsed: Duration::ZERO,
+            })
+            .collect(),
+    };
+
+    let input = match input::load(day, source) {
+        Ok(input) => input,
+        Err(err) if skip_missing => return unsolved(Status::Skipped, &err),
+        Err(err) => return unsolved(Status::Error, &err),
+    };
+    let parsed = match solution.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => return unsolved(Status::Error, &err),
+    };
+
+    let parts = parts
+        .parts()
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match visualize {
+                Some(settings) => {
+                    let prefix = format!("day{day:02}_part{}", part.number());
+                    let mut visualizer = settings.visualizer(&prefix);
+                    let answer = parsed.visualize(part, visualizer.as_mut());
+                    answer.and_then(|answer| visualizer.finish().map(|()| answer))
+                }
+                None => parsed.solve(part),
+            };
+            let elapsed = start.elapsed();
+
+            let mut outcome = PartOutcome {
+                day,
+                part,
+                answer: None,
+                status: Status::Error,
+                expected: None,
+                error: None,
+                elapsed,
+            };
+            match answer {
+                Ok(answer) => {
+                    outcome.status = match answers.check(day, part, &answer) {
+                        Check::Unrecorded => Status::Unrecorded,
+                        Check::Correct => Status::Correct,
+                        Check::Wrong { expected } => {
+                            outcome.expected = Some(expected.to_string());
+                            Status::Wrong
+                        }
+                    };
+                    outcome.answer = Some(answer);
+                }
+                Err(err) => outcome.error = Some(format!("{err:#}")),
+            }
+            outcome
+        })
+        .collect();
+
+    DayOutcome {
+        day,
+        diagnostic: None,
+        parts,
+    }
+}
+
+/// Prints the header of a day and the answer of each part.
+fn print_day(outcome: &DayOutcome) {
+    println!("\nDay {:02}\n------", outcome.day);
+
+    if let Some(diagnostic) = &outcome.diagnostic {
+        println!("{diagnostic}\n");
+        return;
+    }
+
+    for part in &outcome.parts {
+        let (name, elapsed) = (part.part, part.elapsed);
+        match (&part.answer, part.status) {
+            (Some(answer), Status::Correct) => {
+                println!("Part {name}: {answer} ✓ (took: {elapsed:?})")
+            }
+            (Some(answer), Status::Wrong) => println!(
+                "Part {name}: {answer} ✗ expected {} (took: {elapsed:?})",
+                part.expected.as_deref().unwrap_or_default()
+            ),
+            (Some(answer), _) => println!("Part {name}: {answer} (took: {elapsed:?})"),
+            (None, _) => println!(
+                "Part {name}: error: {} (after: {elapsed:?})",
+                part.error.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+
+    println!();
+}
+
+/// Prints one row per part, then how many parts ended up in each status.
+fn print_table(outcomes: &[DayOutcome]) {
+    let rows: Vec<&PartOutcome> = outcomes.iter().flat_map(|day| &day.parts).collect();
+    let width = rows
+        .iter()
+        .filter_map(|part| part.answer.as_ref().map(String::len))
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!(
+        "\n{:<4} {:<4} {:<width$} {:>10}  Status",
+        "Day", "Part", "Answer", "Time"
+    );
+    println!("{}", "-".repeat(4 + 1 + 4 + 1 + width + 1 + 10 + 2 + 6));
+
+    let mut total = Duration::ZERO;
+    for part in &rows {
+        total += part.elapsed;
+        let answer = part.answer.as_deref().unwrap_or("-");
+        let status = match part.status {
+            Status::Correct => "✓".to_string(),
+            Status::Wrong => format!(
+                "✗ expected {}",
+                part.expected.as_deref().unwrap_or_default()
+            ),
+            Status::Unrecorded => "unrecorded".to_string(),
+            Status::Error => format!("error: {}", part.error.as_deref().unwrap_or_default()),
+            Status::Skipped => "skipped, no input".to_string(),
+        };
+        let elapsed = if part.answer.is_some() {
+            format!("{:.2?}", part.elapsed)
+        } else {
+            "-".to_string()
+        };
+        println!(
+            "{:<4} {:<4} {answer:<width$} {elapsed:>10}  {status}",
+            part.day,
+            part.part.number()
+        );
+    }
+
+    let count = |status| rows.iter().filter(|part| part.status == status).count();
+    println!(
+        "\n{} correct, {} wrong, {} unrecorded, {} error(s), {} skipped in {total:.2?}\n",
+        count(Status::Correct),
+        count(Status::Wrong),
+        count(Status::Unrecorded),
+        count(Status::Error),
+        count(Status::Skipped),
+    );
+}
+
+/// Solves the selected parts on the personal input and stores their answers
+/// in `answers.toml`. An answer that differs from the recorded one is only
+/// replaced with `force`.
+pub fn record(days: DaySelection, parts: PartSelection, force: bool) -> Result<(), Error> {
+    let path = answers::default_path();
+    let mut answers = Answers::load(&path)?;
+    let mut failures = 0;
+
+    for solution in days.solutions()? {
+        let day = solution.day();
+        let parsed =
+            input::load(day, &InputSource::Default).and_then(|input| solution.parse(&input));
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                failures += parts.parts().len();
+                println!("Day {day:02}: {}", diagnostic(&err));
+                continue;
+            }
+        };
+
+        for &part in parts.parts() {
+            let answer = match parsed.solve(part) {
+                Ok(answer) => answer,
+                Err(err) => {
+                    failures += 1;
+                    println!("Day {day:02} part {part}: error: {err:#}");
+                    continue;
+                }
+            };
+
+            match answers.check(day, part, &answer) {
+                Check::Correct => println!("Day {day:02} part {part}: {answer} already recorded"),
+                Check::Wrong { expected } if !force => {
+                    failures += 1;
+                    println!(
+                        "Day {day:02} part {part}: {answer} differs from the recorded {expected}, \
+                         pass `--force` to replace it"
+                    );
+                }
+                _ => {
+                    println!("Day {day:02} part {part}: recorded {answer}");
+                    answers.insert(day, part, answer);
+                }
+            }
+        }
+    }
+
+    answers.save(&path)?;
+
+    if failures > 0 {
+        bail!("{failures} part(s) were not recorded");
+    }
+
+    Ok(())
+}
+
+/// Downloads the personal input of the selected days into `puzzle_input/`,
+/// keeping the inputs already there unless `force` is set.
+pub fn fetch(days: DaySelection, force: bool) -> Result<(), Error> {
+    let client = Client::from_env()?;
+
+    for solution in days.solutions()? {
+        let day = solution.day();
+        let path = input::default_path(day);
+        if client::download(&client, day, &path, force)? {
+            println!("Day {day:02}: saved to `{}`", path.display());
+        } else {
+            println!("Day {day:02}: `{}` already holds an input", path.display());
+        }
+    }
+
+    Ok(())
+}
+
+/// Submits `answer`, or the answer computed from the personal input, to a
+/// part of `day`. Every reply goes to `submissions.toml`, and a right answer
+/// is recorded in `answers.toml` too.
+pub fn submit(day: u8, part: Part, answer: Option<String>) -> Result<(), Error> {
+    let solution = DaySelection::Day(day).solutions()?[0];
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let input = input::load(day, &InputSource::Default)?;
+            solution
+                .parse(&input)
+                .and_then(|parsed| parsed.solve(part))
+                .map_err(|err| anyhow!("{}", diagnostic(&err)))?
+        }
+    };
+
+    let client = Client::from_env()?;
+    let path = client::log_path();
+    let mut log = AnswerLog::load(&path)?;
+    let (verdict, sent) = client::submit(&client, &mut log, day, part, &answer)?;
+    if sent {
+        log.save(&path)?;
+        println!("Day {day:02} part {part}: {answer}, {verdict}");
+    } else {
+        println!("Day {day:02} part {part}: {answer}, {verdict} (known from `submissions.toml`, not sent)");
+    }
+
+    if verdict == Verdict::Correct {
+        let path = answers::default_path();
+        let mut answers = Answers::load(&path)?;
+        if answers.insert(day, part, answer.clone()).as_ref() != Some(&answer) {
+            answers.save(&path)?;
+            println!("Day {day:02} part {part}: recorded {answer}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the variants of `day` against its main solvers on generated inputs,
+/// printing the first input they disagree on, minimized.
+pub fn diff(day: u8, options: DiffOptions) -> Result<(), Error> {
+    let solution = DaySelection::Day(day).solutions()?[0];
+
+    // Panics are reported as outcomes, the default hook would bury them
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let found = differential::search(solution, options);
+    std::panic::set_hook(hook);
+
+    match found? {
+        None => {
+            println!(
+                "Day {day:02}: every variant agrees on {} generated input(s) of size {}",
+                options.cases, options.size
+            );
+            Ok(())
+        }
+        Some(disagreement) => {
+            println!("Day {day:02}: {disagreement}");
+            bail!("the variants of day {day:02} disagree")
+        }
+    }
+}
+
+/// Steps through the simulation of a part of `day` in the terminal, see
+/// [`days::step`].
+pub fn step(day: u8, part: Part, source: &InputSource) -> Result<(), Error> {
+    let input = input::load(day, source)?;
+    days::step(day, part, &input)
+}
+
+/// Extracts the examples of `day` from a saved puzzle page, or from the page
+/// downloaded with the session token, into `examples/day_XX.toml`. Existing
+/// examples are only replaced with `force`.
+pub fn extract_examples(day: u8, page: Option<&Path>, force: bool) -> Result<(), Error> {
+    let path = examples::path(day);
+    if path.exists() && !force {
+        bail!(
+            "`{}` already exists, pass `--force` to replace it",
+            path.display()
+        );
+    }
+
+    let page = match page {
+        Some(page) => fs::read_to_string(page)
+            .with_context(|| format!("failed to read `{}`", page.display()))?,
+        None => Client::from_env()?.fetch_page(day)?,
+    };
+    let examples = Examples::extract(&page)?;
+
+    for example in examples.iter() {
+        println!(
+            "Day {day:02} part {}: {} line(s) of input, answer {}",
+            example.part,
+            example.input.lines().count(),
+            example.answer
+        );
+    }
+    examples.save(&path, day)?;
+    println!(
+        "Saved to `{}`, check it against the description",
+        path.display()
+    );
+
+    Ok(())
+}
+
+/// Describes an error for the terminal, showing where a [`ParseError`]
+/// happened in the input.
+fn diagnostic(err: &Error) -> String {
+    match err.downcast_ref::<ParseError>() {
+        Some(parse_error) => parse_error.diagnostic(),
+        None => format!("error: {err:#}"),
+    }
+}
+
+#[test]
+fn reports_parts_as_json() {
+    let outcome = PartOutcome {
+        day: 7,
+        part: Part::Two,
+        answer: Some("11387".to_string()),
+        status: Status::Wrong,
+        expected: Some("11386".to_string()),
+        error: None,
+        elapsed: Duration::from_micros(1500),
+    };
+
+    assert_eq!(
+        serde_json::to_string(&outcome).unwrap(),
+        r#"{"day":7,"part":2,"answer":"11387","status":"wrong","expected":"11386","elapsed_ms":1.5}"#
+    );
+    assert_eq!("json".parse::<Format>().unwrap(), Format::Json);
+    assert!("yaml".parse::<Format>().is_err());
+}
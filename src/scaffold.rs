@@ -0,0 +1,133 @@
+//! Generates the files of a new day from the templates in `templates/`.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Error, Result};
+
+use crate::input::PLACEHOLDER;
+
+const SOLUTION_TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+const BIN_TEMPLATE: &str = include_str!("../templates/bin.rs.tmpl");
+
+/// What [`new_day`] did with one of the files of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Created(PathBuf),
+    /// The file already held the untouched template.
+    Unchanged(PathBuf),
+    /// The file held something else, kept because `force` was not given.
+    Kept(PathBuf),
+    Replaced(PathBuf),
+}
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{DAY}}", &format!("{day:02}"))
+        .replace("{{DAY_NUMBER}}", &day.to_string())
+}
+
+/// Writes the solution module, binary and input file of `day` under `root`,
+/// the repository checkout. Files that already exist are left alone, except
+/// for a solution module still holding the template, or any solution module
+/// when `force` is set.
+///
+/// Every day is already declared in `src/days/mod.rs` and listed in
+/// `days::ALL`, so nothing needs registering.
+pub fn new_day(root: &Path, day: u8, force: bool) -> Result<Vec<Outcome>, Error> {
+    if !(1..=25).contains(&day) {
+        bail!("there is no day {day}, expected a day from 1 to 25");
+    }
+
+    let solution = root.join("src/days").join(format!("day{day:02}.rs"));
+    let bin = root.join("src/bin").join(format!("{day:02}.rs"));
+    let input = root.join("puzzle_input").join(format!("day_{day:02}.txt"));
+
+    let outcome = write_solution(&solution, &render(SOLUTION_TEMPLATE, day), force)?;
+    let refused = matches!(outcome, Outcome::Kept(_));
+
+    let mut outcomes = vec![outcome];
+    if !refused {
+        outcomes.push(create(&bin, &render(BIN_TEMPLATE, day))?);
+        outcomes.push(create(&input, &format!("{PLACEHOLDER}\n"))?);
+    }
+    Ok(outcomes)
+}
+
+fn write_solution(path: &Path, contents: &str, force: bool) -> Result<Outcome, Error> {
+    let existing = match fs::read_to_string(path) {
+        Ok(existing) => existing,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            write(path, contents)?;
+            return Ok(Outcome::Created(path.to_path_buf()));
+        }
+        Err(err) => {
+            return Err(err).with_context(|| format!("failed to read `{}`", path.display()))
+        }
+    };
+
+    if existing == contents {
+        Ok(Outcome::Unchanged(path.to_path_buf()))
+    } else if force {
+        write(path, contents)?;
+        Ok(Outcome::Replaced(path.to_path_buf()))
+    } else {
+        Ok(Outcome::Kept(path.to_path_buf()))
+    }
+}
+
+/// Writes `contents` to `path` unless something is already there.
+fn create(path: &Path, contents: &str) -> Result<Outcome, Error> {
+    if path.exists() {
+        return Ok(Outcome::Unchanged(path.to_path_buf()));
+    }
+    write(path, contents)?;
+    Ok(Outcome::Created(path.to_path_buf()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create `{}`", parent.display()))?;
+    }
+    fs::write(path, contents).with_context(|| format!("failed to write `{}`", path.display()))
+}
+
+#[test]
+fn scaffolds_without_clobbering_solved_days() {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    let solution = root.join("src/days/day07.rs");
+
+    let outcomes = new_day(&root, 7, false).unwrap();
+    assert_eq!(
+        outcomes,
+        [
+            Outcome::Created(solution.clone()),
+            Outcome::Created(root.join("src/bin/07.rs")),
+            Outcome::Created(root.join("puzzle_input/day_07.txt")),
+        ]
+    );
+    let code = fs::read_to_string(&solution).unwrap();
+    assert!(code.contains("pub struct Day07;") && code.contains("const DAY: u8 = 7;"));
+    assert!(!code.contains("{{"));
+
+    assert_eq!(
+        new_day(&root, 7, false).unwrap()[0],
+        Outcome::Unchanged(solution.clone())
+    );
+
+    fs::write(&solution, "// solved\n").unwrap();
+    assert_eq!(
+        new_day(&root, 7, false).unwrap(),
+        [Outcome::Kept(solution.clone())]
+    );
+    assert_eq!(fs::read_to_string(&solution).unwrap(), "// solved\n");
+    assert_eq!(
+        new_day(&root, 7, true).unwrap()[0],
+        Outcome::Replaced(solution.clone())
+    );
+
+    assert!(new_day(&root, 26, false).is_err());
+    fs::remove_dir_all(&root).unwrap();
+}
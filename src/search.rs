@@ -0,0 +1,350 @@
+//! Searches over graphs given by a start and a successor function, so mazes
+//! and maps never have to be turned into an explicit graph first.
+//!
+//! Every search keeps its own stack or queue instead of recursing, so a path
+//! through millions of cells cannot overflow the stack.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+/// What a breadth-first search or Dijkstra's algorithm learned about every
+/// node it reached.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    distances: HashMap<N, C>,
+    /// Every node a node is reached from on one of its shortest paths.
+    predecessors: HashMap<N, Vec<N>>,
+    /// How many distinct shortest paths reach each node.
+    counts: HashMap<N, u64>,
+    /// The reached nodes, closest first.
+    order: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::default(),
+            predecessors: HashMap::default(),
+            counts: HashMap::default(),
+            order: Vec::new(),
+        }
+    }
+
+    /// Cost of the shortest path to `node`, or `None` if it was not reached.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    /// Every reached node, the closest first.
+    pub fn reached(&self) -> &[N] {
+        &self.order
+    }
+
+    /// The nodes before `node` on its shortest paths, none for a start.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// How many distinct shortest paths lead to `node`, 0 if none does.
+    pub fn count(&self, node: &N) -> u64 {
+        self.counts.get(node).copied().unwrap_or(0)
+    }
+
+    /// One shortest path from a start to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.contains(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node on at least one shortest path to `node`, `node` included.
+    pub fn on_shortest_paths(&self, node: &N) -> HashSet<N> {
+        let mut seen = HashSet::default();
+        if !self.contains(node) {
+            return seen;
+        }
+
+        let mut stack = vec![node.clone()];
+        while let Some(node) = stack.pop() {
+            if seen.insert(node.clone()) {
+                stack.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+        seen
+    }
+
+    /// Records a path to `next` through `node` with a total cost of `cost`.
+    fn relax(&mut self, node: &N, next: N, cost: C, better: bool) {
+        let count = self.count(node);
+        if better {
+            self.distances.insert(next.clone(), cost);
+            self.counts.insert(next.clone(), count);
+            self.predecessors.insert(next, vec![node.clone()]);
+        } else {
+            *self.counts.entry(next.clone()).or_default() += count;
+            self.predecessors
+                .entry(next)
+                .or_default()
+                .push(node.clone());
+        }
+    }
+}
+
+/// Breadth-first search from every node of `starts`, each step costing 1.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !paths.contains(&start) {
+            paths.distances.insert(start.clone(), 0);
+            paths.counts.insert(start.clone(), 1);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = paths.distances[&node] + 1;
+        for next in successors(&node) {
+            match paths.distance(&next) {
+                None => {
+                    paths.relax(&node, next.clone(), distance, true);
+                    queue.push_back(next);
+                }
+                Some(known) if known == distance => paths.relax(&node, next, distance, false),
+                Some(_) => {}
+            }
+        }
+        paths.order.push(node);
+    }
+    paths
+}
+
+/// Dijkstra's algorithm from every node of `starts`, `successors` giving the
+/// cost of each step along with the node it leads to.
+///
+/// Costs must not be negative. Path counts also need them to be positive, a
+/// node reached with a step of cost zero may miss some of its paths.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    // Nodes are stored aside so they do not need to be `Ord`
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if !paths.contains(&start) {
+            paths.distances.insert(start.clone(), C::default());
+            paths.counts.insert(start.clone(), 1);
+            heap.push(Reverse((C::default(), nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    let mut settled = HashSet::default();
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if cost > paths.distances[&node] || !settled.insert(node.clone()) {
+            continue;
+        }
+
+        for (next, step) in successors(&node) {
+            let total = cost + step;
+            match paths.distance(&next) {
+                Some(known) if known < total => {}
+                Some(known) if known == total => paths.relax(&node, next, total, false),
+                _ => {
+                    paths.relax(&node, next.clone(), total, true);
+                    heap.push(Reverse((total, nodes.len())));
+                    nodes.push(next);
+                }
+            }
+        }
+        paths.order.push(node);
+    }
+    paths
+}
+
+/// A* search from `start` to the first node satisfying `is_goal`, returning
+/// the path, both ends included, and its cost.
+///
+/// `heuristic` must never overestimate the cost left to a goal, or the path
+/// found may not be the shortest. It need not be consistent: a node reached
+/// again by a cheaper path is searched again from there.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    paths.distances.insert(start.clone(), C::default());
+    paths.counts.insert(start.clone(), 1);
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut nodes = vec![start];
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        // A cheaper path to the node was found after this one was queued
+        if cost > paths.distances[&node] {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((paths.path_to(&node)?, cost));
+        }
+
+        for (next, step) in successors(&node) {
+            let total = cost + step;
+            if paths.distance(&next).is_none_or(|known| total < known) {
+                paths.relax(&node, next.clone(), total, true);
+                heap.push(Reverse((total + heuristic(&next), total, nodes.len())));
+                nodes.push(next);
+            }
+        }
+    }
+    None
+}
+
+/// Depth-first search from `start`, returning every reachable node in the
+/// order it is first visited.
+pub fn dfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::default();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        stack.extend(
+            successors(&node)
+                .into_iter()
+                .filter(|next| !seen.contains(next)),
+        );
+        order.push(node);
+    }
+    order
+}
+
+#[test]
+fn finds_shortest_paths_on_a_maze() {
+    use crate::grid::Grid;
+    use crate::point::Point;
+
+    let maze: Grid<char> = "\
+S...#
+.##.#
+....E
+"
+    .parse()
+    .unwrap();
+    let start = maze.find(&'S').unwrap();
+    let end = maze.find(&'E').unwrap();
+    let open = |point: &Point| -> Vec<Point> {
+        maze.neighbours(*point)
+            .filter(|&(_, &cell)| cell != '#')
+            .map(|(next, _)| next)
+            .collect()
+    };
+
+    let paths = bfs([start], open);
+    assert_eq!(paths.distance(&end), Some(6));
+    // Around the wall from the top or from the bottom
+    assert_eq!(paths.count(&end), 2);
+    assert_eq!(paths.on_shortest_paths(&end).len(), 11);
+    let path = paths.path_to(&end).unwrap();
+    assert_eq!((path[0], path[6]), (start, end));
+    assert!(path.windows(2).all(|step| step[0].manhattan(step[1]) == 1));
+    assert_eq!(paths.reached().len(), 11);
+    assert_eq!(paths.distance(&Point::new(4, 0)), None);
+
+    // The bottom row costs 5 a cell, so the path along the top is the only best one
+    let weighted = |point: &Point| -> Vec<(Point, u32)> {
+        open(point)
+            .into_iter()
+            .map(|next| (next, if next.y == 2 { 5 } else { 1 }))
+            .collect()
+    };
+    let paths = dijkstra([start], weighted);
+    assert_eq!(paths.distance(&end), Some(14));
+    assert_eq!(paths.count(&end), 1);
+    assert_eq!(paths.path_to(&end).unwrap()[1], Point::new(1, 0));
+
+    let found = astar(
+        start,
+        weighted,
+        |point| point.manhattan(end) as u32,
+        |&p| p == end,
+    );
+    assert_eq!(found.map(|(path, cost)| (path.len(), cost)), Some((7, 14)));
+    assert_eq!(
+        astar(start, weighted, |_| 0, |&p| p == Point::new(4, 0)),
+        None
+    );
+
+    assert_eq!(dfs(start, open).len(), 11);
+}
+
+#[test]
+fn astar_searches_nodes_again_when_cheaper() {
+    // `a` looks far from the goal, so `c` is first searched from `b`, then
+    // found cheaper through `a`
+    let edges = |node: &char| -> Vec<(char, u32)> {
+        match node {
+            's' => vec![('a', 1), ('b', 2)],
+            'a' | 'b' => vec![('c', 1)],
+            'c' => vec![('g', 10)],
+            _ => vec![],
+        }
+    };
+    let heuristic = |node: &char| if *node == 'a' { 9 } else { 0 };
+
+    let found = astar('s', edges, heuristic, |&node| node == 'g');
+    assert_eq!(found, Some((vec!['s', 'a', 'c', 'g'], 12)));
+}
+
+#[test]
+fn deep_searches_do_not_overflow() {
+    let chain = |&n: &u32| (n < 1_000_000).then_some(n + 1);
+
+    assert_eq!(dfs(0, chain).len(), 1_000_001);
+    let paths = bfs([0], chain);
+    assert_eq!(paths.distance(&1_000_000), Some(1_000_000));
+    assert_eq!(paths.path_to(&1_000_000).unwrap().len(), 1_000_001);
+}
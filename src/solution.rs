@@ -0,0 +1,194 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{bail, Error, Result};
+
+use crate::generate::Rng;
+use crate::parse::ParseError;
+use crate::visualize::Visualizer;
+
+/// One day of the calendar.
+///
+/// Implement it on a unit struct in `src/days/` and register that struct in
+/// [`crate::days::ALL`]. The runner takes care of the header, the timing and
+/// the error reporting, so a day only has to parse its input and provide its
+/// two answers.
+pub trait Solution {
+    /// Day of the calendar, from 1 to 25.
+    const DAY: u8;
+
+    /// Puzzle input once parsed, shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    fn part_1(input: &Self::Input) -> Result<String, Error>;
+
+    fn part_2(input: &Self::Input) -> Result<String, Error>;
+
+    /// Other ways to solve the parts, checked against [`Self::part_1`] and
+    /// [`Self::part_2`] by `aoc diff` and timed next to them by `aoc bench`.
+    fn variants() -> Vec<Variant<Self::Input>> {
+        Vec::new()
+    }
+
+    /// A random valid input of about `size`, for days with a generator. What
+    /// `size` counts is up to the day, see [`crate::generate`].
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        let _ = (size, rng);
+        None
+    }
+
+    /// Solves part 1 while showing its steps to `visualizer`, for days that
+    /// have something to show. The others just solve it.
+    fn visualize_part_1(
+        input: &Self::Input,
+        visualizer: &mut dyn Visualizer,
+    ) -> Result<String, Error> {
+        let _ = visualizer;
+        Self::part_1(input)
+    }
+
+    /// Solves part 2 while showing its steps to `visualizer`, like
+    /// [`Self::visualize_part_1`].
+    fn visualize_part_2(
+        input: &Self::Input,
+        visualizer: &mut dyn Visualizer,
+    ) -> Result<String, Error> {
+        let _ = visualizer;
+        Self::part_2(input)
+    }
+}
+
+/// An alternative solver of one part, like a faster algorithm kept next to
+/// the straightforward one.
+pub struct Variant<I> {
+    /// Short name, unique within the day.
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&I) -> Result<String, Error>,
+}
+
+/// Object-safe view of a [`Solution`], so every day can be stored in one table.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+
+    /// Parses the puzzle input, returning a handle that solves either part.
+    /// A [`ParseError`] coming out of it names the day.
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, Error>;
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String>;
+
+    /// The part and name of each [`Variant`] of the day.
+    fn variants(&self) -> Vec<(Part, &'static str)>;
+}
+
+/// Puzzle input parsed by a [`DynSolution`].
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Result<String, Error>;
+
+    fn visualize(&self, part: Part, visualizer: &mut dyn Visualizer) -> Result<String, Error>;
+
+    /// Solves `part` with the [`Variant`] called `name`.
+    fn solve_variant(&self, part: Part, name: &str) -> Result<String, Error>;
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn solve(&self, part: Part) -> Result<String, Error> {
+        match part {
+            Part::One => S::part_1(&self.0),
+            Part::Two => S::part_2(&self.0),
+        }
+    }
+
+    fn visualize(&self, part: Part, visualizer: &mut dyn Visualizer) -> Result<String, Error> {
+        match part {
+            Part::One => S::visualize_part_1(&self.0, visualizer),
+            Part::Two => S::visualize_part_2(&self.0, visualizer),
+        }
+    }
+
+    fn solve_variant(&self, part: Part, name: &str) -> Result<String, Error> {
+        let Some(variant) = S::variants()
+            .into_iter()
+            .find(|variant| variant.part == part && variant.name == name)
+        else {
+            bail!(
+                "day {:02} part {} has no variant `{name}`",
+                S::DAY,
+                part.number()
+            );
+        };
+        (variant.solve)(&self.0)
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync + 'static,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, Error> {
+        let input = S::parse(input).map_err(|mut err| {
+            if let Some(parse_error) = err.downcast_mut::<ParseError>() {
+                parse_error.day = Some(S::DAY);
+            }
+            err
+        })?;
+        Ok(Box::new(ParsedInput::<S>(input)))
+    }
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        S::generate(size, rng)
+    }
+
+    fn variants(&self) -> Vec<(Part, &'static str)> {
+        S::variants()
+            .iter()
+            .map(|variant| (variant.part, variant.name))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// 1 or 2, as the puzzle pages and the files in the repository count.
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.write_str("One"),
+            Part::Two => f.write_str("Two"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => bail!("expected `1` or `2`, got `{s}`"),
+        }
+    }
+}
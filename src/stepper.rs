@@ -0,0 +1,315 @@
+//! Steps through a simulation one tick at a time in the terminal, forwards
+//! and backwards, instead of sprinkling `println!`s in a solver.
+//!
+//! A day exposes its simulation as a [`Simulation`], and [`run`] lets you
+//! move around its ticks with the keyboard. Going back replays from the
+//! closest earlier checkpoint, so simulations only ever step forwards.
+
+use std::io::{self, Write};
+
+use anyhow::{Error, Result};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, terminal};
+
+use crate::visualize::{Frame, Terminal};
+
+/// A state that advances in discrete ticks.
+pub trait Simulation: Clone {
+    /// Advances one tick, or returns `false` and leaves the state as it is
+    /// once the simulation is over.
+    fn tick(&mut self) -> bool;
+
+    /// The current state, drawing the extras `overlays` turns on.
+    fn frame(&self, overlays: Overlays) -> Frame;
+
+    /// Named values worth keeping an eye on, shown under the map.
+    fn counters(&self) -> Vec<(&'static str, String)>;
+}
+
+/// Extras drawn on top of the map, toggled while stepping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overlays {
+    /// Cells visited so far.
+    pub visited: bool,
+    /// The direction things are heading, as an arrow.
+    pub direction: bool,
+}
+
+impl Default for Overlays {
+    fn default() -> Self {
+        Self {
+            visited: true,
+            direction: true,
+        }
+    }
+}
+
+/// Ticks between two saved states. Going back replays at most this many.
+const CHECKPOINT: usize = 64;
+
+/// A simulation and the way back through the ticks it went through.
+#[derive(Debug, Clone)]
+pub struct Stepper<S> {
+    current: S,
+    tick: usize,
+    /// The state at every multiple of [`CHECKPOINT`] reached so far.
+    checkpoints: Vec<S>,
+    /// The last tick, once the simulation reached it.
+    end: Option<usize>,
+}
+
+impl<S: Simulation> Stepper<S> {
+    pub fn new(start: S) -> Self {
+        Self {
+            current: start,
+            tick: 0,
+            checkpoints: Vec::new(),
+            end: None,
+        }
+    }
+
+    pub fn state(&self) -> &S {
+        &self.current
+    }
+
+    pub fn tick(&self) -> usize {
+        self.tick
+    }
+
+    pub fn end(&self) -> Option<usize> {
+        self.end
+    }
+
+    /// Moves up to `ticks` ticks forwards, stopping at the end.
+    pub fn forward(&mut self, ticks: usize) {
+        for _ in 0..ticks {
+            if self.end == Some(self.tick) {
+                return;
+            }
+            if self.tick.is_multiple_of(CHECKPOINT)
+                && self.checkpoints.len() == self.tick / CHECKPOINT
+            {
+                self.checkpoints.push(self.current.clone());
+            }
+            if !self.current.tick() {
+                self.end = Some(self.tick);
+                return;
+            }
+            self.tick += 1;
+        }
+    }
+
+    /// Moves up to `ticks` ticks backwards, stopping at the start.
+    pub fn back(&mut self, ticks: usize) {
+        self.go_to(self.tick.saturating_sub(ticks));
+    }
+
+    /// Moves to `tick`, or to the end if the simulation stops before it.
+    pub fn go_to(&mut self, tick: usize) {
+        if tick < self.tick {
+            let checkpoint = tick / CHECKPOINT;
+            self.current = self.checkpoints[checkpoint].clone();
+            self.tick = checkpoint * CHECKPOINT;
+        }
+        self.forward(tick - self.tick);
+    }
+}
+
+/// What a key press asks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Forward(usize),
+    Back(usize),
+    GoTo(usize),
+    End,
+    ToggleVisited,
+    ToggleDirection,
+    Quit,
+}
+
+const HELP: &str = "→/l/space: step  ←/h: back  N→ N←: jump N ticks  Ng: go to tick N  \
+                    G: end  v: visited  d: direction  q: quit";
+
+/// Steps through `start` in the terminal until `q` is pressed.
+pub fn run<S: Simulation>(title: &str, start: S) -> Result<(), Error> {
+    let mut stepper = Stepper::new(start);
+    let mut overlays = Overlays::default();
+    let mut count = String::new();
+
+    let _screen = Screen::enter()?;
+    loop {
+        draw(title, &stepper, overlays, &count)?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        if let KeyCode::Char(digit @ '0'..='9') = key.code {
+            count.push(digit);
+            continue;
+        }
+
+        let n = count.parse().ok();
+        count.clear();
+        let Some(command) = command(key, n) else {
+            continue;
+        };
+        match command {
+            Command::Forward(ticks) => stepper.forward(ticks),
+            Command::Back(ticks) => stepper.back(ticks),
+            Command::GoTo(tick) => stepper.go_to(tick),
+            Command::End => stepper.forward(usize::MAX),
+            Command::ToggleVisited => overlays.visited = !overlays.visited,
+            Command::ToggleDirection => overlays.direction = !overlays.direction,
+            Command::Quit => return Ok(()),
+        }
+    }
+}
+
+/// The command of `key`, `n` being the count typed before it, if any.
+fn command(key: KeyEvent, n: Option<usize>) -> Option<Command> {
+    let command = match key.code {
+        KeyCode::Right | KeyCode::Char('l' | ' ') => Command::Forward(n.unwrap_or(1)),
+        KeyCode::Left | KeyCode::Char('h') => Command::Back(n.unwrap_or(1)),
+        KeyCode::Char('g') => Command::GoTo(n.unwrap_or(0)),
+        KeyCode::Char('G') | KeyCode::End => Command::End,
+        KeyCode::Char('v') => Command::ToggleVisited,
+        KeyCode::Char('d') => Command::ToggleDirection,
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Command::Quit,
+        KeyCode::Char('q') | KeyCode::Esc => Command::Quit,
+        _ => return None,
+    };
+    Some(command)
+}
+
+fn draw<S: Simulation>(
+    title: &str,
+    stepper: &Stepper<S>,
+    overlays: Overlays,
+    count: &str,
+) -> Result<(), Error> {
+    let state = stepper.state();
+    let tick = match stepper.end() {
+        Some(end) if end == stepper.tick() => format!("tick {} (end)", stepper.tick()),
+        _ => format!("tick {}", stepper.tick()),
+    };
+    let counters: Vec<String> = state
+        .counters()
+        .into_iter()
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect();
+    let on = |shown| if shown { "on" } else { "off" };
+    let count = match count {
+        "" => String::new(),
+        count => format!("  jump: {count}"),
+    };
+
+    let mut text = format!("{title} — {tick}\n");
+    text.push_str(&Terminal::render(&state.frame(overlays)));
+    text.push_str(&counters.join("  "));
+    text.push_str(&format!(
+        "\nvisited: {}  direction: {}{count}\n{HELP}\n",
+        on(overlays.visited),
+        on(overlays.direction)
+    ));
+
+    // Raw mode does not go back to the start of the line on `\n`
+    let mut out = io::stdout();
+    execute!(
+        out,
+        cursor::MoveTo(0, 0),
+        terminal::Clear(terminal::ClearType::All)
+    )?;
+    out.write_all(text.replace('\n', "\r\n").as_bytes())?;
+    out.flush()?;
+    Ok(())
+}
+
+/// Raw mode on the alternate screen, left when dropped, even on a panic.
+struct Screen;
+
+impl Screen {
+    fn enter() -> Result<Self, Error> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[test]
+fn steps_back_by_replaying_checkpoints() {
+    use crate::grid::Grid;
+
+    /// Counts up to 200, remembering how often it ticked.
+    #[derive(Clone)]
+    struct Counter {
+        value: usize,
+        ticks: std::rc::Rc<std::cell::Cell<usize>>,
+    }
+
+    impl Simulation for Counter {
+        fn tick(&mut self) -> bool {
+            if self.value == 200 {
+                return false;
+            }
+            self.value += 1;
+            self.ticks.set(self.ticks.get() + 1);
+            true
+        }
+
+        fn frame(&self, _: Overlays) -> Frame {
+            Frame::new(self.value.to_string(), Grid::new(1, 1, vec!['.']))
+        }
+
+        fn counters(&self) -> Vec<(&'static str, String)> {
+            vec![("value", self.value.to_string())]
+        }
+    }
+
+    let ticks = std::rc::Rc::default();
+    let mut stepper = Stepper::new(Counter {
+        value: 0,
+        ticks: std::rc::Rc::clone(&ticks),
+    });
+
+    stepper.forward(150);
+    assert_eq!((stepper.tick(), stepper.state().value), (150, 150));
+    stepper.back(1);
+    assert_eq!(stepper.state().value, 149);
+    // Replayed from the checkpoint at 128
+    assert_eq!(ticks.get(), 150 + 149 - 128);
+
+    stepper.go_to(10);
+    assert_eq!(stepper.state().value, 10);
+    stepper.back(50);
+    assert_eq!(stepper.tick(), 0);
+
+    stepper.go_to(1000);
+    assert_eq!((stepper.tick(), stepper.end()), (200, Some(200)));
+    stepper.forward(1);
+    assert_eq!(stepper.state().counters(), [("value", "200".to_string())]);
+
+    let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+    assert_eq!(
+        command(key(KeyCode::Right), Some(30)),
+        Some(Command::Forward(30))
+    );
+    assert_eq!(
+        command(key(KeyCode::Char('h')), None),
+        Some(Command::Back(1))
+    );
+    assert_eq!(
+        command(key(KeyCode::Char('g')), Some(7)),
+        Some(Command::GoTo(7))
+    );
+    assert_eq!(command(key(KeyCode::Char('x')), None), None);
+}
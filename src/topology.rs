@@ -0,0 +1,295 @@
+//! What happens at the edge of the space a simulation runs in: things fall
+//! off a [`Bounded`] one, come back on the other side of a [`Toroidal`] one,
+//! and never reach the edge of an [`Unbounded`] one.
+//!
+//! A simulation written against [`Topology`] runs under any of them, and a
+//! [`SparseGrid`] stores cells wherever its topology puts them.
+
+use std::collections::hash_map;
+
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::point::Point;
+
+/// The edge rule of a space.
+pub trait Topology {
+    /// Where `point` really is: itself, wrapped around, or `None` if it falls
+    /// outside the space.
+    fn place(&self, point: Point) -> Option<Point>;
+
+    /// Where something at `point` moving by `velocity` every tick is after
+    /// `ticks` ticks, computed in one go. A place beyond the range of `i32`
+    /// is outside the space.
+    fn advance(&self, point: Point, velocity: Point, ticks: i32) -> Option<Point> {
+        let axis = |position: i32, speed: i32| {
+            let moved = i64::from(position) + i64::from(speed) * i64::from(ticks);
+            i32::try_from(moved).ok()
+        };
+        let moved = Point::new(axis(point.x, velocity.x)?, axis(point.y, velocity.y)?);
+        self.place(moved)
+    }
+
+    /// Whether `point` is inside the space as it is, without wrapping.
+    fn contains(&self, point: Point) -> bool {
+        self.place(point) == Some(point)
+    }
+
+    /// The up to four places sharing an edge with `point`, clockwise from up.
+    fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.place(point + direction.offset()))
+    }
+}
+
+/// A `width` by `height` rectangle from the origin, which things fall off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounded {
+    width: i32,
+    height: i32,
+}
+
+impl Bounded {
+    pub const fn new(width: i32, height: i32) -> Self {
+        Self { width, height }
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+}
+
+impl Topology for Bounded {
+    fn place(&self, point: Point) -> Option<Point> {
+        let inside = (0..self.width).contains(&point.x) && (0..self.height).contains(&point.y);
+        inside.then_some(point)
+    }
+}
+
+/// A `width` by `height` rectangle from the origin whose opposite edges are
+/// joined, so leaving on one side comes back on the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Toroidal {
+    width: i32,
+    height: i32,
+}
+
+impl Toroidal {
+    pub const fn new(width: i32, height: i32) -> Self {
+        Self { width, height }
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+}
+
+impl Topology for Toroidal {
+    fn place(&self, point: Point) -> Option<Point> {
+        Some(Point::new(
+            point.x.rem_euclid(self.width),
+            point.y.rem_euclid(self.height),
+        ))
+    }
+
+    fn advance(&self, point: Point, velocity: Point, ticks: i32) -> Option<Point> {
+        // Wide enough that a long run cannot overflow
+        let axis = |position: i32, speed: i32, size: i32| {
+            let moved = i64::from(position) + i64::from(speed) * i64::from(ticks);
+            moved.rem_euclid(i64::from(size)) as i32
+        };
+        Some(Point::new(
+            axis(point.x, velocity.x, self.width),
+            axis(point.y, velocity.y, self.height),
+        ))
+    }
+}
+
+/// The whole plane, where every point is its own place.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Unbounded;
+
+impl Topology for Unbounded {
+    fn place(&self, point: Point) -> Option<Point> {
+        Some(point)
+    }
+}
+
+/// Cells stored by position in a hash map, so only the occupied ones take up
+/// memory, each placed by the topology `S`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T, S = Unbounded> {
+    topology: S,
+    cells: HashMap<Point, T>,
+}
+
+impl<T> SparseGrid<T> {
+    /// An empty grid over the whole plane.
+    pub fn unbounded() -> Self {
+        Self::new(Unbounded)
+    }
+}
+
+impl<T, S: Topology> SparseGrid<T, S> {
+    pub fn new(topology: S) -> Self {
+        Self {
+            topology,
+            cells: HashMap::default(),
+        }
+    }
+
+    pub fn topology(&self) -> &S {
+        &self.topology
+    }
+
+    /// Stores `value` where `point` really is, which it returns, or drops
+    /// `value` and returns `None` if `point` falls outside the space.
+    pub fn insert(&mut self, point: Point, value: T) -> Option<Point> {
+        let place = self.topology.place(point)?;
+        self.cells.insert(place, value);
+        Some(place)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&self.topology.place(point)?)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&self.topology.place(point)?)
+    }
+
+    /// The cell at `point`, set to `T::default()` first if it is empty, or
+    /// `None` if `point` falls outside the space.
+    pub fn get_or_default(&mut self, point: Point) -> Option<&mut T>
+    where
+        T: Default,
+    {
+        let place = self.topology.place(point)?;
+        Some(self.cells.entry(place).or_default())
+    }
+
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&self.topology.place(point)?)
+    }
+
+    /// The number of occupied cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Every occupied cell with its position, in no particular order.
+    pub fn iter(&self) -> hash_map::Iter<'_, Point, T> {
+        self.cells.iter()
+    }
+
+    /// The occupied cells among the up to four sharing an edge with `point`.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.topology
+            .neighbours(point)
+            .filter_map(|next| Some((next, self.cells.get(&next)?)))
+    }
+
+    /// The top left and bottom right corners of the occupied cells.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), point| {
+            (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            )
+        }))
+    }
+
+    /// The occupied cells as a dense [`Grid`] covering their [`bounds`],
+    /// `empty` filling the rest, along with the position its origin stands
+    /// for.
+    ///
+    /// [`bounds`]: Self::bounds
+    pub fn to_grid(&self, empty: T) -> Option<(Grid<T>, Point)>
+    where
+        T: Clone,
+    {
+        let (min, max) = self.bounds()?;
+        let size = max - min + Point::new(1, 1);
+        let grid = Grid::from_fn(size.x as usize, size.y as usize, |point| {
+            self.cells.get(&(point + min)).unwrap_or(&empty).clone()
+        });
+        Some((grid, min))
+    }
+}
+
+#[test]
+fn places_points_by_edge_rule() {
+    let bounded = Bounded::new(11, 7);
+    let toroidal = Toroidal::new(11, 7);
+    let (start, velocity) = (Point::new(2, 4), Point::new(2, -3));
+
+    assert_eq!(bounded.advance(start, velocity, 1), Some(Point::new(4, 1)));
+    assert_eq!(bounded.advance(start, velocity, 2), None);
+    assert_eq!(toroidal.advance(start, velocity, 5), Some(Point::new(1, 3)));
+    assert_eq!(
+        toroidal.advance(start, velocity, i32::MAX),
+        toroidal.place(start + velocity * (i32::MAX % 77))
+    );
+    assert_eq!(
+        Unbounded.advance(start, velocity, 5),
+        Some(Point::new(12, -11))
+    );
+    assert_eq!(Unbounded.advance(start, velocity, i32::MAX), None);
+    assert_eq!(bounded.advance(start, velocity, i32::MIN), None);
+
+    let corner = Point::new(0, 0);
+    assert_eq!(bounded.neighbours(corner).count(), 2);
+    assert_eq!(
+        toroidal.neighbours(corner).collect::<Vec<_>>(),
+        [
+            Point::new(0, 6),
+            Point::new(1, 0),
+            Point::new(0, 1),
+            Point::new(10, 0)
+        ]
+    );
+    assert!(!toroidal.contains(Point::new(11, 0)));
+    assert!(Unbounded.contains(Point::new(-5, 1000)));
+}
+
+#[test]
+fn stores_sparse_cells() {
+    let mut wrapped = SparseGrid::new(Toroidal::new(4, 4));
+    assert_eq!(
+        wrapped.insert(Point::new(-1, 5), '#'),
+        Some(Point::new(3, 1))
+    );
+    assert_eq!(wrapped.get(Point::new(7, 1)), Some(&'#'));
+    assert_eq!(wrapped.neighbours(Point::new(0, 1)).count(), 1);
+
+    let mut fenced = SparseGrid::new(Bounded::new(4, 4));
+    assert_eq!(fenced.insert(Point::new(-1, 5), '#'), None);
+    assert!(fenced.is_empty());
+
+    let mut plane = SparseGrid::unbounded();
+    for point in [Point::new(-2, 3), Point::new(1, 1), Point::new(-2, 3)] {
+        *plane.get_or_default(point).unwrap() += 1;
+    }
+    assert_eq!(plane.len(), 2);
+    assert_eq!(plane.remove(Point::new(1, 1)), Some(1));
+    plane.insert(Point::new(0, 2), 5);
+    let (grid, origin) = plane.to_grid(0).unwrap();
+    assert_eq!(origin, Point::new(-2, 2));
+    assert_eq!(grid, Grid::new(3, 2, vec![0, 0, 5, 2, 0, 0]));
+}
@@ -0,0 +1,216 @@
+//! Disjoint sets of elements numbered from 0, merged as connections are
+//! found, and the connected components of a [`Grid`] built from them.
+
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::point::Point;
+
+/// A partition of `0..len` into sets, with path compression and union by
+/// rank so every operation takes nearly constant time.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    /// Elements in each set, kept up to date for the roots only.
+    size: Vec<usize>,
+    sets: usize,
+}
+
+impl UnionFind {
+    /// `len` elements, each in a set of its own.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            size: vec![1; len],
+            sets: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The number of disjoint sets.
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// The element standing for the set of `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // Point everything on the way straight at the root
+        let mut current = element;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        root
+    }
+
+    /// Merges the sets of `a` and `b`, returning `false` if they already
+    /// were the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        // The shallower tree goes under the deeper one
+        let (root, child) = if self.rank[a] < self.rank[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+        self.sets -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of elements in the set of `element`.
+    pub fn size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// Every set, ordered by its smallest element, each in increasing order.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut label = vec![usize::MAX; self.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.sets);
+
+        for element in 0..self.len() {
+            let root = self.find(element);
+            if label[root] == usize::MAX {
+                label[root] = groups.len();
+                groups.push(Vec::new());
+            }
+            groups[label[root]].push(element);
+        }
+        groups
+    }
+}
+
+/// The connected components of a grid, cells sharing an edge being connected
+/// when the predicate given to [`components`] says so.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    /// The component of every cell, numbered in reading order of their first
+    /// cell.
+    pub labels: Grid<usize>,
+    /// The cells of each component, in reading order.
+    pub cells: Vec<Vec<Point>>,
+}
+
+/// Labels the connected components of `grid`, where two cells sharing an
+/// edge are connected if `connected` holds for them. A cell connected to
+/// none of its neighbours is a component of its own.
+pub fn components<T>(grid: &Grid<T>, mut connected: impl FnMut(&T, &T) -> bool) -> Components {
+    let width = grid.width();
+    let mut sets = UnionFind::new(width * grid.height());
+
+    for (point, cell) in grid.iter() {
+        // Looking right and down reaches every pair of neighbours once
+        for direction in [Direction::Right, Direction::Down] {
+            let next = point + direction.offset();
+            if let Some(other) = grid.get(next) {
+                if connected(cell, other) {
+                    sets.union(index(point, width), index(next, width));
+                }
+            }
+        }
+    }
+
+    let groups = sets.groups();
+    let mut labels = vec![0; width * grid.height()];
+    for (label, group) in groups.iter().enumerate() {
+        for &element in group {
+            labels[element] = label;
+        }
+    }
+    let cells = groups
+        .into_iter()
+        .map(|group| {
+            group
+                .into_iter()
+                .map(|element| Point::from_index(element, width))
+                .collect()
+        })
+        .collect();
+
+    Components {
+        labels: Grid::new(width, grid.height(), labels),
+        cells,
+    }
+}
+
+fn index(point: Point, width: usize) -> usize {
+    point.to_index(width).expect("the point is inside the grid")
+}
+
+#[test]
+fn merges_sets() {
+    let mut sets = UnionFind::new(6);
+    assert!(sets.union(0, 1));
+    assert!(sets.union(2, 3));
+    assert!(sets.union(1, 3));
+    assert!(!sets.union(0, 2));
+
+    assert!(sets.connected(0, 3));
+    assert!(!sets.connected(0, 4));
+    assert_eq!((sets.sets(), sets.size(2), sets.size(5)), (3, 4, 1));
+    assert_eq!(sets.groups(), [vec![0, 1, 2, 3], vec![4], vec![5]]);
+
+    // Union by rank never builds a long chain, so link one by hand: a single
+    // lookup from its far end points every element straight at the root
+    let mut chain = UnionFind::new(100_000);
+    for element in 1..chain.len() {
+        chain.parent[element] = element - 1;
+    }
+    assert_eq!(chain.find(99_999), 0);
+    assert!(chain.parent.iter().all(|&parent| parent == 0));
+
+    let mut merged = UnionFind::new(100_000);
+    for element in 1..merged.len() {
+        merged.union(element - 1, element);
+    }
+    assert_eq!(merged.size(0), 100_000);
+    assert_eq!(merged.sets(), 1);
+}
+
+#[test]
+fn labels_grid_components() {
+    let grid: Grid<char> = "\
+AAB
+CAB
+CCA
+"
+    .parse()
+    .unwrap();
+
+    let components = components(&grid, |a, b| a == b);
+    assert_eq!(components.cells.len(), 4);
+    assert_eq!(
+        components.cells[0],
+        [Point::new(0, 0), Point::new(1, 0), Point::new(1, 1)]
+    );
+    assert_eq!(components.cells[3], [Point::new(2, 2)]);
+    assert_eq!(
+        components.labels,
+        Grid::new(3, 3, vec![0, 0, 1, 2, 0, 1, 2, 2, 3])
+    );
+}
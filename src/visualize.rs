@@ -0,0 +1,431 @@
+//! Lets a solver show its progress without printing anything itself.
+//!
+//! A solver hands [`Frame`]s to a [`Visualizer`], which the runner picks with
+//! `--visualize`. Solving normally goes through [`NoVisualizer`], so solvers
+//! stay silent and only build frames when [`Visualizer::enabled`] says someone
+//! is watching.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Error, Result};
+
+use crate::grid::Grid;
+use crate::image::{GifWriter, Image, Palette};
+use crate::point::Point;
+
+/// How a cell of a frame stands out from the rest of the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    /// Being looked at in this step.
+    Active,
+    /// Part of the answer found so far.
+    Found,
+}
+
+/// A snapshot of a solver's state: a map with some cells marked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub grid: Grid<char>,
+    pub marks: Vec<(Point, Mark)>,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, grid: Grid<char>) -> Self {
+        Self {
+            caption: caption.into(),
+            grid,
+            marks: Vec::new(),
+        }
+    }
+
+    pub fn mark(mut self, points: impl IntoIterator<Item = Point>, mark: Mark) -> Self {
+        self.marks
+            .extend(points.into_iter().map(|point| (point, mark)));
+        self
+    }
+
+    /// The mark of `point`, the last one winning when it was marked twice.
+    pub fn mark_at(&self, point: Point) -> Option<Mark> {
+        self.marks
+            .iter()
+            .rev()
+            .find(|(marked, _)| *marked == point)
+            .map(|&(_, mark)| mark)
+    }
+}
+
+/// Receives the frames a solver emits.
+pub trait Visualizer {
+    /// Whether frames are wanted at all. Solvers check it before building one.
+    fn enabled(&self) -> bool {
+        true
+    }
+
+    fn frame(&mut self, frame: Frame);
+
+    /// Called once the part is solved, to flush whatever was buffered.
+    fn finish(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Ignores every frame, the visualizer used when solving normally.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoVisualizer;
+
+impl Visualizer for NoVisualizer {
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn frame(&mut self, _: Frame) {}
+}
+
+/// Redraws each frame in place in the terminal, with ANSI colors, pausing
+/// `delay` between frames.
+#[derive(Debug, Clone)]
+pub struct Terminal {
+    pub delay: Duration,
+}
+
+impl Default for Terminal {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(50),
+        }
+    }
+}
+
+impl Terminal {
+    /// The frame as colored text in a box: marked cells in yellow when
+    /// active and red when found, empty space in gray and the rest in cyan.
+    pub fn render(frame: &Frame) -> String {
+        let width = frame.grid.width();
+        let mut text = format!("{}\n╔{}╗\n", frame.caption, "═".repeat(width + 2));
+
+        for (y, row) in frame.grid.rows().enumerate() {
+            text.push_str("║ ");
+            for (x, &cell) in row.iter().enumerate() {
+                let color = match frame.mark_at(Point::new(x as i32, y as i32)) {
+                    Some(Mark::Active) => "33",
+                    Some(Mark::Found) => "31",
+                    None if cell == '.' => "90",
+                    None => "36",
+                };
+                text.push_str(&format!("\x1b[{color}m{cell}\x1b[0m"));
+            }
+            text.push_str(" ║\n");
+        }
+
+        text.push_str(&format!("╚{}╝\n", "═".repeat(width + 2)));
+        text
+    }
+}
+
+impl Visualizer for Terminal {
+    fn frame(&mut self, frame: Frame) {
+        // Clear the screen and go back to its top left corner
+        print!("\x1b[2J\x1b[H{}", Self::render(&frame));
+        let _ = io::stdout().flush();
+        thread::sleep(self.delay);
+    }
+}
+
+/// Pixels per cell in the pictures of frames.
+const SCALE: usize = 4;
+
+/// How [`FrameDump`] stores each frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FrameFormat {
+    /// The caption and the map as plain text.
+    #[default]
+    Text,
+    Ppm,
+    Png,
+}
+
+impl FrameFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            FrameFormat::Text => "txt",
+            FrameFormat::Ppm => "ppm",
+            FrameFormat::Png => "png",
+        }
+    }
+}
+
+impl FromStr for FrameFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "txt" => Ok(FrameFormat::Text),
+            "ppm" => Ok(FrameFormat::Ppm),
+            "png" => Ok(FrameFormat::Png),
+            _ => bail!("expected `txt`, `ppm` or `png`, got `{s}`"),
+        }
+    }
+}
+
+/// Writes each frame to `dir/<prefix>_00001.txt` and onwards, or to `.ppm`
+/// or `.png` pictures.
+#[derive(Debug)]
+pub struct FrameDump {
+    dir: PathBuf,
+    prefix: String,
+    format: FrameFormat,
+    palette: Palette,
+    count: usize,
+    error: Option<Error>,
+}
+
+impl FrameDump {
+    pub fn new(dir: impl Into<PathBuf>, prefix: impl Into<String>, format: FrameFormat) -> Self {
+        Self {
+            dir: dir.into(),
+            prefix: prefix.into(),
+            format,
+            palette: Palette::default(),
+            count: 0,
+            error: None,
+        }
+    }
+
+    fn write(&self, frame: &Frame) -> Result<(), Error> {
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("failed to create `{}`", self.dir.display()))?;
+        let name = format!(
+            "{}_{:05}.{}",
+            self.prefix,
+            self.count,
+            self.format.extension()
+        );
+        let path = self.dir.join(name);
+        let context = || format!("failed to write `{}`", path.display());
+
+        match self.format {
+            FrameFormat::Text => {
+                fs::write(&path, format!("{}\n{}", frame.caption, frame.grid)).with_context(context)
+            }
+            FrameFormat::Ppm | FrameFormat::Png => {
+                let image = Image::from_frame(frame, &self.palette, SCALE);
+                let out = BufWriter::new(File::create(&path).with_context(context)?);
+                match self.format {
+                    FrameFormat::Ppm => image.write_ppm(out),
+                    _ => image.write_png(out),
+                }
+                .with_context(context)
+            }
+        }
+    }
+}
+
+impl Visualizer for FrameDump {
+    fn frame(&mut self, frame: Frame) {
+        // Keep the first failure for `finish`, there is no point going on
+        if self.error.is_some() {
+            return;
+        }
+        self.count += 1;
+        if let Err(err) = self.write(&frame) {
+            self.error = Some(err);
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => {
+                println!("Wrote {} frame(s) to `{}`", self.count, self.dir.display());
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Records the frames as an animated GIF, written to `path` as they come.
+pub struct GifRecorder {
+    path: PathBuf,
+    palette: Palette,
+    /// Time each frame stays on screen, in hundredths of a second.
+    delay: u16,
+    writer: Option<GifWriter<BufWriter<File>>>,
+    count: usize,
+    error: Option<Error>,
+}
+
+impl GifRecorder {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            palette: Palette::default(),
+            delay: 5,
+            writer: None,
+            count: 0,
+            error: None,
+        }
+    }
+
+    fn write(&mut self, frame: &Frame) -> Result<(), Error> {
+        let image = Image::from_frame(frame, &self.palette, SCALE);
+        let writer = match &mut self.writer {
+            Some(writer) => writer,
+            None => {
+                if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                    fs::create_dir_all(dir)
+                        .with_context(|| format!("failed to create `{}`", dir.display()))?;
+                }
+                let out = File::create(&self.path)
+                    .with_context(|| format!("failed to create `{}`", self.path.display()))?;
+                let writer = GifWriter::new(
+                    BufWriter::new(out),
+                    image.width(),
+                    image.height(),
+                    self.delay,
+                )?;
+                self.writer.insert(writer)
+            }
+        };
+        writer.write(&image)
+    }
+}
+
+impl Visualizer for GifRecorder {
+    fn frame(&mut self, frame: Frame) {
+        if self.error.is_some() {
+            return;
+        }
+        self.count += 1;
+        if let Err(err) = self.write(&frame) {
+            self.error = Some(err);
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        // Dropping the encoder writes the end of the file
+        self.writer = None;
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => {
+                println!("Wrote {} frame(s) to `{}`", self.count, self.path.display());
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Passes one frame out of `every` on to another visualizer, and always the
+/// last one, so long simulations make reasonably sized animations.
+pub struct Sampled {
+    inner: Box<dyn Visualizer>,
+    every: usize,
+    seen: usize,
+    skipped: Option<Frame>,
+}
+
+impl Sampled {
+    pub fn new(inner: Box<dyn Visualizer>, every: usize) -> Self {
+        Self {
+            inner,
+            every: every.max(1),
+            seen: 0,
+            skipped: None,
+        }
+    }
+}
+
+impl Visualizer for Sampled {
+    fn enabled(&self) -> bool {
+        self.inner.enabled()
+    }
+
+    fn frame(&mut self, frame: Frame) {
+        if self.seen.is_multiple_of(self.every) {
+            self.skipped = None;
+            self.inner.frame(frame);
+        } else {
+            self.skipped = Some(frame);
+        }
+        self.seen += 1;
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        if let Some(last) = self.skipped.take() {
+            self.inner.frame(last);
+        }
+        self.inner.finish()
+    }
+}
+
+/// Where `--visualize` sends the frames: `terminal`, a file ending in `.gif`,
+/// or a directory to dump them into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Terminal,
+    Gif(PathBuf),
+    Dump(PathBuf),
+}
+
+impl FromStr for Target {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "terminal" => Ok(Target::Terminal),
+            gif if gif.ends_with(".gif") => Ok(Target::Gif(PathBuf::from(gif))),
+            dir => Ok(Target::Dump(PathBuf::from(dir))),
+        }
+    }
+}
+
+/// Everything `--visualize` and its companion flags choose.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    pub target: Target,
+    /// How dumped frames are stored.
+    pub format: FrameFormat,
+    /// Keep one frame out of this many.
+    pub every: usize,
+}
+
+impl Settings {
+    /// A visualizer for one part. `prefix` names its frames when they are
+    /// dumped, and is added to the name of a GIF.
+    pub fn visualizer(&self, prefix: &str) -> Box<dyn Visualizer> {
+        let visualizer: Box<dyn Visualizer> = match &self.target {
+            Target::Terminal => Box::new(Terminal::default()),
+            Target::Gif(path) => {
+                let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+                Box::new(GifRecorder::new(
+                    path.with_file_name(format!("{stem}_{prefix}.gif")),
+                ))
+            }
+            Target::Dump(dir) => Box::new(FrameDump::new(dir, prefix, self.format)),
+        };
+        Box::new(Sampled::new(visualizer, self.every))
+    }
+}
+
+#[test]
+fn renders_marked_cells() {
+    let grid: Grid<char> = "a.\n.b\n".parse().unwrap();
+    let frame = Frame::new("step 1", grid)
+        .mark([Point::new(0, 0)], Mark::Active)
+        .mark([Point::new(1, 1)], Mark::Found);
+
+    assert_eq!(frame.mark_at(Point::new(1, 1)), Some(Mark::Found));
+    assert_eq!(frame.mark_at(Point::new(1, 0)), None);
+    assert_eq!(
+        Terminal::render(&frame),
+        "step 1\n╔════╗\n\
+         ║ \x1b[33ma\x1b[0m\x1b[90m.\x1b[0m ║\n\
+         ║ \x1b[90m.\x1b[0m\x1b[31mb\x1b[0m ║\n\
+         ╚════╝\n"
+    );
+}